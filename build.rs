@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Generates one `fixture_test!` invocation per `inputs/dayN_test_K.txt` so that adding an
+/// example fixture adds a test without touching any code.
+fn main() {
+    println!("cargo:rerun-if-changed=inputs");
+
+    let mut fixtures: Vec<(u64, u64)> = fs::read_dir("inputs")
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    let (day, index) = name
+                        .strip_prefix("day")?
+                        .strip_suffix(".txt")?
+                        .split_once("_test_")?;
+                    Some((day.parse().ok()?, index.parse().ok()?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    fixtures.sort();
+
+    let tests: String = fixtures
+        .iter()
+        .map(|(day, index)| {
+            format!(
+                "fixture_test!(day{}_fixture_{}, {}, {});\n",
+                day, index, day, index
+            )
+        })
+        .collect();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("fixture_tests.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::error::ParseError;
+use crate::{Answer, AocError, Day, Part};
+
+/// Known-good answers, stored as TOML with one table per day:
+///
+/// ```toml
+/// [day2]
+/// part1 = "2237"
+/// part2 = "66681"
+/// ```
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<u64, [Option<String>; 2]>,
+}
+
+/// How a freshly computed answer compares to the recorded one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Check {
+    Match,
+    Changed {
+        expected: String,
+    },
+    Unrecorded,
+    /// The part isn't solved yet, so there's nothing to compare or record.
+    Unsolved,
+}
+
+impl Answers {
+    /// Loads the answers file at `path`, which may not exist yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let days = parse(&contents).map_err(|error| AocError::InvalidFile {
+            path: path.clone(),
+            error,
+        })?;
+
+        Ok(Self { path, days })
+    }
+
+    pub fn get(&self, day: u64, part: Part) -> Option<&str> {
+        self.days.get(&day)?[index(part)].as_deref()
+    }
+
+    pub fn set(&mut self, day: u64, part: Part, answer: &Answer) {
+        self.days.entry(day).or_default()[index(part)] = Some(answer.to_string());
+    }
+
+    pub fn check(&self, day: u64, part: Part, answer: &Answer) -> Check {
+        if *answer == Answer::Unsolved {
+            return Check::Unsolved;
+        }
+
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Check::Match,
+            Some(expected) => Check::Changed {
+                expected: expected.to_string(),
+            },
+            None => Check::Unrecorded,
+        }
+    }
+
+    /// Solves both parts of `day` on `input` and checks each answer against the record.
+    pub fn verify(
+        &self,
+        day: &dyn Day,
+        input: &str,
+    ) -> Result<Vec<(Part, Answer, Check)>, AocError> {
+        Ok(day
+            .run(input, None)?
+            .into_iter()
+            .map(|(part, answer)| {
+                let check = self.check(day.day(), part, &answer);
+                (part, answer, check)
+            })
+            .collect())
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(&self.path, self.to_toml())
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+
+        for (day, parts) in &self.days {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+
+            writeln!(out, "[day{}]", day).unwrap();
+            for (i, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    writeln!(out, "part{} = {:?}", i + 1, answer).unwrap();
+                }
+            }
+        }
+
+        out
+    }
+}
+
+fn index(part: Part) -> usize {
+    part.number() as usize - 1
+}
+
+fn parse(s: &str) -> Result<BTreeMap<u64, [Option<String>; 2]>, ParseError> {
+    let mut days: BTreeMap<u64, [Option<String>; 2]> = BTreeMap::new();
+    let mut current = None;
+
+    for (i, line) in s.lines().enumerate() {
+        let error =
+            |message: &str, token: &str| ParseError::new(message, line, token).on_line(i + 1);
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(table) = trimmed.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+            let day = table
+                .strip_prefix("day")
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| error("expected a [dayN] table", table))?;
+            days.entry(day).or_default();
+            current = Some(day);
+            continue;
+        }
+
+        let (key, value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| error("expected `partN = answer`", trimmed))?;
+        let (key, value) = (key.trim(), value.trim());
+
+        let part = match key {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            _ => return Err(error("expected part1 or part2", key)),
+        };
+
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => quoted
+                .strip_suffix('"')
+                .ok_or_else(|| error("unterminated string", value))?,
+            None => value,
+        };
+
+        let day = current.ok_or_else(|| error("answer outside of a [dayN] table", key))?;
+        days.entry(day).or_default()[index(part)] = Some(value.to_string());
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day6::Day6;
+    use crate::load_test_input;
+
+    #[test]
+    fn round_trip() {
+        let toml = "[day2]\npart1 = \"8\"\n\n[day10]\npart1 = 4\npart2 = \"ABC\"\n";
+        let days = parse(toml).unwrap();
+
+        let answers = Answers {
+            path: PathBuf::new(),
+            days,
+        };
+        assert_eq!(Some("4"), answers.get(10, Part::One));
+        assert_eq!(None, answers.get(2, Part::Two));
+        assert_eq!(toml.replace("= 4", "= \"4\""), answers.to_toml());
+
+        let err = parse("part1 = 1").err().unwrap();
+        assert_eq!((1, 1), (err.line, err.column));
+        let err = parse("[day1]\n\npart3 = 1").err().unwrap();
+        assert_eq!((3, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn verify() {
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            days: BTreeMap::new(),
+        };
+        answers.set(6, Part::One, &Answer::from(288));
+        answers.set(6, Part::Two, &Answer::from(1));
+
+        let input = load_test_input(6).unwrap();
+        let checks: Vec<Check> = answers
+            .verify(&Day6, &input)
+            .unwrap()
+            .into_iter()
+            .map(|(_, _, check)| check)
+            .collect();
+
+        assert_eq!(
+            vec![
+                Check::Match,
+                Check::Changed {
+                    expected: "1".to_string()
+                }
+            ],
+            checks
+        );
+        assert_eq!(Check::Unrecorded, answers.check(7, Part::One, &1.into()));
+        assert_eq!(
+            Check::Unsolved,
+            answers.check(6, Part::One, &Answer::Unsolved)
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{load_problem_input, AocError, Day};
+
+/// Timings from a single parse and solve of both parts.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty set of samples. The standard deviation is the population one.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let n = sorted.len() as f64;
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Benchmark {
+    pub day: u64,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Benchmark {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Benchmarks `day` against its problem input.
+pub fn bench_day(day: &dyn Day, iterations: usize) -> Result<Benchmark, AocError> {
+    let input = load_problem_input(day.day())?;
+
+    bench_input(day, &input, iterations)
+}
+
+/// Parses and solves `input` `iterations` times (at least once), timing each step.
+pub fn bench_input(day: &dyn Day, input: &str, iterations: usize) -> Result<Benchmark, AocError> {
+    let iterations = iterations.max(1);
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let sample = day.time(input)?;
+        parse.push(sample.parse);
+        part1.push(sample.part1);
+        part2.push(sample.part2);
+    }
+
+    Ok(Benchmark {
+        day: day.day(),
+        iterations,
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+/// Renders results as an aligned, human-readable table.
+pub fn table(results: &[Benchmark]) -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "{:>3}  {:<5}  {:>10}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "day", "step", "iterations", "min", "median", "mean", "stddev"
+    )
+    .unwrap();
+
+    for result in results {
+        for (step, stats) in result.steps() {
+            writeln!(
+                out,
+                "{:>3}  {:<5}  {:>10}  {:>12}  {:>12}  {:>12}  {:>12}",
+                result.day,
+                step,
+                result.iterations,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+/// Renders results as a JSON array with all durations in nanoseconds.
+pub fn json(results: &[Benchmark]) -> String {
+    let days: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let steps: Vec<String> = result
+                .steps()
+                .iter()
+                .map(|(step, stats)| {
+                    format!(
+                        "\"{}\":{{\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+                        step,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.mean.as_nanos(),
+                        stats.stddev.as_nanos()
+                    )
+                })
+                .collect();
+
+            format!(
+                "{{\"day\":{},\"iterations\":{},{}}}",
+                result.day,
+                result.iterations,
+                steps.join(",")
+            )
+        })
+        .collect();
+
+    format!("[{}]", days.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day6::Day6;
+    use crate::load_test_input;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(4)]);
+
+        assert_eq!(ms(2), stats.min);
+        assert_eq!(ms(4), stats.median);
+        assert_eq!(ms(4), stats.mean);
+        assert_eq!(Duration::from_nanos(1_414_214), stats.stddev);
+
+        assert_eq!(ms(3), Stats::from_samples(&[ms(1), ms(3), ms(9)]).median);
+    }
+
+    #[test]
+    fn bench_and_render() {
+        let input = load_test_input(6).unwrap();
+        let result = bench_input(&Day6, &input, 3).unwrap();
+
+        assert_eq!(6, result.day);
+        assert_eq!(3, result.iterations);
+
+        let table = table(&[result]);
+        assert_eq!(4, table.lines().count());
+        assert!(table.lines().nth(2).unwrap().contains("part1"));
+
+        let json = json(&[result]);
+        assert!(json.starts_with("[{\"day\":6,\"iterations\":3,\"parse\":{\"min_ns\":"));
+        assert!(json.ends_with("}}]"));
+    }
+}
@@ -0,0 +1,287 @@
+use std::env;
+use std::process;
+
+use aoc2023::answers::{Answers, Check};
+use aoc2023::input::{crate_dir, input_dir};
+use aoc2023::scaffold;
+use aoc2023::submit::Submitter;
+use aoc2023::{
+    bench, days, load_problem_input, run_day, solve_day, AocError, Config, Day, InputSource, Part,
+};
+
+const USAGE: &str = "\
+usage: aoc run <day|all> [--part <1|2>] [--input <path|->]
+       aoc bench <day|all> [--iterations <n>] [--json] [--input <path|->]
+       aoc submit <day> <part>
+       aoc verify [day|all] [--update]
+       aoc new <day>";
+
+enum CliError {
+    Usage(String),
+    Aoc(AocError),
+    Failed(String),
+}
+
+impl From<AocError> for CliError {
+    fn from(e: AocError) -> Self {
+        CliError::Aoc(e)
+    }
+}
+
+impl From<String> for CliError {
+    fn from(msg: String) -> Self {
+        CliError::Usage(msg)
+    }
+}
+
+impl From<&str> for CliError {
+    fn from(msg: &str) -> Self {
+        CliError::Usage(msg.to_string())
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => {}
+        Err(CliError::Usage(msg)) => {
+            eprintln!("error: {}", msg);
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+        Err(CliError::Aoc(e)) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+        Err(CliError::Failed(msg)) => {
+            eprintln!("{}", msg);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    match args.first().map(String::as_str) {
+        Some("run") => cmd_run(&args[1..]),
+        Some("bench") => cmd_bench(&args[1..]),
+        Some("submit") => cmd_submit(&args[1..]),
+        Some("verify") => cmd_verify(&args[1..]),
+        Some("new") => cmd_new(&args[1..]),
+        Some(cmd) => Err(format!("unknown command: {}", cmd).into()),
+        None => Err("missing command".into()),
+    }
+}
+
+fn cmd_run(args: &[String]) -> Result<(), CliError> {
+    let mut selection = None;
+    let mut part = None;
+    let mut source = InputSource::Default;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = args.next().ok_or("--part requires a value")?;
+                let n: u8 = value
+                    .parse()
+                    .map_err(|_| format!("invalid part: {}", value))?;
+                part = Some(Part::try_from(n)?);
+            }
+            "--input" | "-i" => source = input_source(args.next())?,
+            _ if selection.is_none() => selection = Some(select_days(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let selection = selection.ok_or("missing day")?;
+    check_single_day(&selection, &source)?;
+
+    for day in selection {
+        run_day(day, part, &source)?;
+    }
+
+    Ok(())
+}
+
+fn cmd_bench(args: &[String]) -> Result<(), CliError> {
+    let mut selection = None;
+    let mut iterations = 100;
+    let mut json = false;
+    let mut source = InputSource::Default;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                let value = args.next().ok_or("--iterations requires a value")?;
+                iterations = value
+                    .parse()
+                    .map_err(|_| format!("invalid iteration count: {}", value))?;
+            }
+            "--json" => json = true,
+            "--input" | "-i" => source = input_source(args.next())?,
+            _ if selection.is_none() => selection = Some(select_days(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let selection = selection.ok_or("missing day")?;
+    check_single_day(&selection, &source)?;
+
+    let results = selection
+        .into_iter()
+        .map(|day| bench::bench_input(day, &source.load(day.day())?, iterations))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if json {
+        println!("{}", bench::json(&results));
+    } else {
+        print!("{}", bench::table(&results));
+    }
+
+    Ok(())
+}
+
+fn cmd_submit(args: &[String]) -> Result<(), CliError> {
+    let [day, part] = args else {
+        return Err("submit takes a day and a part".into());
+    };
+
+    let n: u64 = day.parse().map_err(|_| format!("invalid day: {}", day))?;
+    let day = days::find(n).ok_or_else(|| format!("day {} is not registered", n))?;
+    let part = part
+        .parse::<u8>()
+        .map_err(|_| format!("invalid part: {}", part))?;
+    let part = Part::try_from(part)?;
+
+    let (_, answer) = solve_day(day, Some(part), &InputSource::Default)?.remove(0);
+    println!("Day {} Part {}: {}", n, part.number(), answer);
+
+    let verdict = Submitter::new(input_dir(), Config::load()?).submit(n, part, &answer)?;
+    println!("{}", verdict);
+
+    Ok(())
+}
+
+/// Reruns days against their real inputs and compares with `answers.toml`. With `--update`,
+/// answers that aren't recorded yet are written to the file; changed answers never are.
+/// Days without an input are reported and skipped, and a day that fails doesn't stop the
+/// rest from being checked.
+fn cmd_verify(args: &[String]) -> Result<(), CliError> {
+    let mut selection = None;
+    let mut update = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--update" => update = true,
+            _ if selection.is_none() => selection = Some(select_days(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg).into()),
+        }
+    }
+
+    let selection = selection.unwrap_or_else(|| days::DAYS.to_vec());
+    let mut answers = Answers::load(crate_dir().join("answers.toml"))?;
+    let mut changed = 0;
+    let mut failed = 0;
+    let mut recorded = 0;
+
+    for day in selection {
+        let checks = load_problem_input(day.day()).and_then(|input| answers.verify(day, &input));
+        let checks = match checks {
+            Ok(checks) => checks,
+            Err(AocError::MissingInput { .. }) => {
+                println!("Day {}: no input", day.day());
+                continue;
+            }
+            Err(e) => {
+                println!("Day {}: error: {}", day.day(), e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for (part, answer, check) in checks {
+            let status = match &check {
+                Check::Match => "ok".to_string(),
+                Check::Changed { expected } => format!("CHANGED, expected {}", expected),
+                Check::Unrecorded if update => "recorded".to_string(),
+                Check::Unrecorded => "unrecorded".to_string(),
+                Check::Unsolved => "not solved yet".to_string(),
+            };
+            println!(
+                "Day {} Part {}: {} ({})",
+                day.day(),
+                part.number(),
+                answer,
+                status
+            );
+
+            match check {
+                Check::Changed { .. } => changed += 1,
+                Check::Unrecorded if update => {
+                    answers.set(day.day(), part, &answer);
+                    recorded += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save().map_err(AocError::from)?;
+    }
+
+    let mut problems = Vec::new();
+    if changed > 0 {
+        problems.push(format!("{} answer(s) changed", changed));
+    }
+    if failed > 0 {
+        problems.push(format!("{} day(s) failed", failed));
+    }
+
+    if !problems.is_empty() {
+        return Err(CliError::Failed(problems.join(", ")));
+    }
+
+    Ok(())
+}
+
+fn cmd_new(args: &[String]) -> Result<(), CliError> {
+    let [day] = args else {
+        return Err("new takes a day".into());
+    };
+
+    let day: u64 = day.parse().map_err(|_| format!("invalid day: {}", day))?;
+
+    for path in scaffold::new_day(crate_dir(), day)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn input_source(arg: Option<&String>) -> Result<InputSource, CliError> {
+    let arg = arg.ok_or("--input requires a path, or - for stdin")?;
+
+    Ok(InputSource::from_arg(arg))
+}
+
+fn check_single_day(selection: &[&dyn Day], source: &InputSource) -> Result<(), CliError> {
+    if selection.len() > 1 && *source != InputSource::Default {
+        return Err("--input can only be used with a single day".into());
+    }
+
+    Ok(())
+}
+
+fn select_days(arg: &str) -> Result<Vec<&'static dyn Day>, String> {
+    if arg == "all" {
+        return Ok(days::DAYS.to_vec());
+    }
+
+    let n: u64 = arg.parse().map_err(|_| format!("invalid day: {}", arg))?;
+    let day = days::find(n).ok_or_else(|| format!("day {} is not registered", n))?;
+
+    Ok(vec![day])
+}
@@ -0,0 +1,199 @@
+use crate::{Grid, Point, Rect};
+
+/// Which neighbors count as touching.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Connectivity {
+    /// Up, down, left and right.
+    Cardinal,
+    /// The cardinal neighbors and the diagonals too.
+    Diagonal,
+}
+
+impl Connectivity {
+    fn neighbors(self, p: &Point) -> Vec<Point> {
+        match self {
+            Connectivity::Cardinal => p.adjacent(),
+            Connectivity::Diagonal => p.adjacent_diagonal(),
+        }
+    }
+}
+
+/// A connected region of cells.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Component {
+    /// The points in the order they were reached, starting from the seed.
+    pub points: Vec<Point>,
+    /// The number of cell edges between the region and anything outside it, including the
+    /// edge of the grid.
+    pub perimeter: usize,
+    pub bounds: Rect,
+}
+
+impl Component {
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+}
+
+/// Every component of a grid, and which one each point belongs to.
+#[derive(Debug, Clone)]
+pub struct Components {
+    /// The index into `components` of each point's component, if it's in one.
+    pub labels: Grid<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    pub fn at(&self, p: Point) -> Option<&Component> {
+        let label = (*self.labels.get(p)?)?;
+        Some(&self.components[label])
+    }
+}
+
+impl<T> Grid<T> {
+    /// Labels the connected regions of cells for which `matches` holds.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        mut matches: impl FnMut(Point, &T) -> bool,
+    ) -> Components {
+        let inside = Grid::from_vec(
+            self.width(),
+            self.height(),
+            self.iter().map(|(p, v)| matches(p, v)).collect(),
+        );
+        let mut labels = Grid::filled(self.width(), self.height(), None);
+        let mut components = Vec::new();
+
+        for p in self.points() {
+            if inside[p] && labels[p].is_none() {
+                let component = spread(&inside, p, connectivity, &mut labels, components.len());
+                components.push(component);
+            }
+        }
+
+        Components { labels, components }
+    }
+
+    /// Fills outwards from `seed` until stopped by walls or the edge of the grid. Returns
+    /// `None` if the seed is itself a wall or outside the grid.
+    pub fn flood_fill(
+        &self,
+        seed: Point,
+        connectivity: Connectivity,
+        mut is_wall: impl FnMut(Point, &T) -> bool,
+    ) -> Option<Component> {
+        let inside = Grid::from_vec(
+            self.width(),
+            self.height(),
+            self.iter().map(|(p, v)| !is_wall(p, v)).collect(),
+        );
+        if !inside.get(seed).copied()? {
+            return None;
+        }
+
+        let mut labels = Grid::filled(self.width(), self.height(), None);
+        Some(spread(&inside, seed, connectivity, &mut labels, 0))
+    }
+}
+
+/// Collects the component of `inside` cells containing `seed`, labelling each with `label`.
+fn spread(
+    inside: &Grid<bool>,
+    seed: Point,
+    connectivity: Connectivity,
+    labels: &mut Grid<Option<usize>>,
+    label: usize,
+) -> Component {
+    let mut points = vec![seed];
+    let mut perimeter = 0;
+    labels[seed] = Some(label);
+
+    let mut i = 0;
+    while let Some(&p) = points.get(i) {
+        i += 1;
+        // Cardinal neighbors are always connected, so any that aren't inside are an edge
+        perimeter += p
+            .adjacent()
+            .into_iter()
+            .filter(|q| !inside.get(*q).copied().unwrap_or(false))
+            .count();
+
+        for q in connectivity.neighbors(&p) {
+            if inside.get(q).copied().unwrap_or(false) && labels[q].is_none() {
+                labels[q] = Some(label);
+                points.push(q);
+            }
+        }
+    }
+
+    Component {
+        bounds: Rect::bounding(points.iter().copied()).unwrap(),
+        points,
+        perimeter,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GARDEN: &str = "\
+##..
+#..#
+..##
+.#.#
+";
+
+    #[test]
+    fn label() {
+        let grid = Grid::from_chars(GARDEN);
+
+        let sizes = |found: &Components| -> Vec<usize> {
+            found.components.iter().map(Component::area).collect()
+        };
+
+        let found = grid.components(Connectivity::Cardinal, |_, c| *c == '#');
+        assert_eq!(vec![3, 4, 1], sizes(&found));
+        assert_eq!(8, found.components[0].perimeter);
+        assert_eq!(
+            Rect::new(Point::new(2, 1), Point::new(3, 3)),
+            found.components[1].bounds
+        );
+        assert_eq!(Some(1), found.labels[Point::new(2, 2)]);
+        assert_eq!(None, found.at(Point::new(1, 1)));
+
+        // (1, 3) only touches the second region diagonally
+        let found = grid.components(Connectivity::Diagonal, |_, c| *c == '#');
+        assert_eq!(vec![3, 5], sizes(&found));
+        assert_eq!(found.at(Point::new(1, 3)), found.at(Point::new(3, 1)));
+    }
+
+    #[test]
+    fn fill_enclosed() {
+        let grid = Grid::from_chars("#####\n#..##\n#.#.#\n#####\n");
+        let wall = |_, c: &char| *c == '#';
+
+        let inner = grid
+            .flood_fill(Point::new(1, 1), Connectivity::Cardinal, wall)
+            .unwrap();
+        assert_eq!(3, inner.area());
+        assert_eq!(8, inner.perimeter);
+        assert_eq!(Rect::new(Point::new(1, 1), Point::new(2, 2)), inner.bounds);
+
+        // The lone cell at (3, 2) only joins up diagonally
+        let diagonal = grid
+            .flood_fill(Point::new(1, 1), Connectivity::Diagonal, wall)
+            .unwrap();
+        assert_eq!(4, diagonal.area());
+
+        assert_eq!(
+            None,
+            grid.flood_fill(Point::new(0, 0), Connectivity::Cardinal, wall)
+        );
+        assert_eq!(
+            None,
+            grid.flood_fill(Point::new(9, 0), Connectivity::Cardinal, wall)
+        );
+    }
+}
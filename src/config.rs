@@ -0,0 +1,102 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::input::crate_dir;
+use crate::AocError;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle server, read from a `key = value` config file and
+/// overridden by environment variables:
+///
+/// | key        | variable       |
+/// |------------|----------------|
+/// | `session`  | `AOC_SESSION`  |
+/// | `base_url` | `AOC_BASE_URL` |
+///
+/// The config file is `.aocrc` in the crate root unless `AOC_CONFIG` names another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, AocError> {
+        let path = var("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| crate_dir().join(".aocrc"));
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(s) => Config::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some(session) = var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    /// Parses config file contents. Blank lines, `#` comments and unknown keys are ignored.
+    pub fn parse(s: &str) -> Self {
+        let mut config = Config::default();
+
+        for line in s.lines().map(|l| l.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().trim_matches('"').to_string();
+
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => {}
+            }
+        }
+
+        config
+    }
+}
+
+/// Reads an environment variable, treating an empty value as unset.
+fn var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let config =
+            Config::parse("# comment\nsession = \"abc123\"\n\nbase_url=http://localhost:8080\n");
+
+        assert_eq!(Some("abc123".to_string()), config.session);
+        assert_eq!("http://localhost:8080", config.base_url);
+
+        assert_eq!(Config::default(), Config::parse("bogus\nother = 1"));
+    }
+}
@@ -0,0 +1,263 @@
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Point;
+
+/// A point with integer coordinates along some fixed number of axes. Implementors supply
+/// the coordinates; neighbors and distances are derived from them, so 2D, 3D and
+/// higher-dimensional puzzles can share code.
+pub trait Coordinates:
+    Copy
+    + Eq
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<i64, Output = Self>
+{
+    const DIMENSIONS: usize;
+
+    /// The coordinate along `axis`, counting from zero.
+    fn coord(&self, axis: usize) -> i64;
+
+    /// Builds a point from the coordinate along each axis.
+    fn from_fn(f: impl FnMut(usize) -> i64) -> Self;
+
+    fn origin() -> Self {
+        Self::from_fn(|_| 0)
+    }
+
+    /// The neighbors one step away along a single axis.
+    fn adjacent(&self) -> Vec<Self> {
+        (0..Self::DIMENSIONS)
+            .flat_map(|axis| {
+                [-1, 1].map(|d| Self::from_fn(|i| self.coord(i) + if i == axis { d } else { 0 }))
+            })
+            .collect()
+    }
+
+    /// Every neighbor at most one step away along each axis, diagonals included.
+    fn adjacent_diagonal(&self) -> Vec<Self> {
+        (0..3usize.pow(Self::DIMENSIONS as u32))
+            .map(|n| Self::from_fn(|i| self.coord(i) + (n / 3usize.pow(i as u32) % 3) as i64 - 1))
+            .filter(|p| p != self)
+            .collect()
+    }
+
+    fn manhattan(&self, other: &Self) -> u64 {
+        (0..Self::DIMENSIONS)
+            .map(|i| self.coord(i).abs_diff(other.coord(i)))
+            .sum()
+    }
+
+    fn chebyshev(&self, other: &Self) -> u64 {
+        (0..Self::DIMENSIONS)
+            .map(|i| self.coord(i).abs_diff(other.coord(i)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn distance_squared(&self, other: &Self) -> u128 {
+        (0..Self::DIMENSIONS)
+            .map(|i| (self.coord(i).abs_diff(other.coord(i)) as u128).pow(2))
+            .sum()
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_fn(|i| self.coord(i).signum())
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        checked(|i| self.coord(i).checked_add(other.coord(i)))
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        checked(|i| self.coord(i).checked_sub(other.coord(i)))
+    }
+
+    fn checked_mul(&self, n: i64) -> Option<Self> {
+        checked(|i| self.coord(i).checked_mul(n))
+    }
+}
+
+/// Builds a point from fallible coordinates, or `None` if any of them fails.
+fn checked<P: Coordinates>(mut f: impl FnMut(usize) -> Option<i64>) -> Option<P> {
+    let mut failed = false;
+    let p = P::from_fn(|i| {
+        f(i).unwrap_or_else(|| {
+            failed = true;
+            0
+        })
+    });
+
+    (!failed).then_some(p)
+}
+
+impl Coordinates for Point {
+    const DIMENSIONS: usize = 2;
+
+    fn coord(&self, axis: usize) -> i64 {
+        [self.x, self.y][axis]
+    }
+
+    fn from_fn(mut f: impl FnMut(usize) -> i64) -> Self {
+        Point::new(f(0), f(1))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Coordinates for Point3 {
+    const DIMENSIONS: usize = 3;
+
+    fn coord(&self, axis: usize) -> i64 {
+        [self.x, self.y, self.z][axis]
+    }
+
+    fn from_fn(mut f: impl FnMut(usize) -> i64) -> Self {
+        Point3::new(f(0), f(1), f(2))
+    }
+}
+
+/// A point with any number of dimensions, for puzzles beyond three.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct PointN<const D: usize>(pub [i64; D]);
+
+impl<const D: usize> Coordinates for PointN<D> {
+    const DIMENSIONS: usize = D;
+
+    fn coord(&self, axis: usize) -> i64 {
+        self.0[axis]
+    }
+
+    fn from_fn(f: impl FnMut(usize) -> i64) -> Self {
+        PointN(std::array::from_fn(f))
+    }
+}
+
+/// Implements the arithmetic operators coordinate by coordinate. `Point` has its own.
+macro_rules! coordinate_ops {
+    ($ty:ty $(, const $d:ident)?) => {
+        impl<$(const $d: usize)?> Add for $ty {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::from_fn(|i| self.coord(i) + other.coord(i))
+            }
+        }
+
+        impl<$(const $d: usize)?> Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self::from_fn(|i| self.coord(i) - other.coord(i))
+            }
+        }
+
+        impl<$(const $d: usize)?> Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::from_fn(|i| -self.coord(i))
+            }
+        }
+
+        impl<$(const $d: usize)?> Mul<i64> for $ty {
+            type Output = Self;
+
+            fn mul(self, n: i64) -> Self {
+                Self::from_fn(|i| self.coord(i) * n)
+            }
+        }
+
+        impl<$(const $d: usize)?> AddAssign for $ty {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$(const $d: usize)?> SubAssign for $ty {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+coordinate_ops!(Point3);
+coordinate_ops!(PointN<D>, const D);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Written once against the trait, used in every dimension.
+    fn nearest<P: Coordinates>(points: &[P]) -> P {
+        *points
+            .iter()
+            .min_by_key(|p| p.manhattan(&P::origin()))
+            .unwrap()
+    }
+
+    #[test]
+    fn shared_operations() {
+        assert_eq!(
+            Point::new(1, -1),
+            nearest(&[Point::new(3, 4), Point::new(1, -1)])
+        );
+        assert_eq!(
+            Point3::new(0, 0, 2),
+            nearest(&[Point3::new(1, 1, 1), Point3::new(0, 0, 2)])
+        );
+
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(-1, 0, 5);
+        assert_eq!(Point3::new(0, 2, 8), a + b);
+        assert_eq!(Point3::new(2, 2, -2), a - b);
+        assert_eq!(Point3::new(-2, -4, -6), -a * 2);
+        assert_eq!(6, a.manhattan(&b));
+        assert_eq!(2, a.chebyshev(&b));
+        assert_eq!(12, a.distance_squared(&b));
+        assert_eq!(Point3::new(1, 1, -1), (a - b).signum());
+
+        let mut c = PointN([1, 2, 3, 4]);
+        c += PointN([1, 1, 1, 1]);
+        assert_eq!(PointN([2, 3, 4, 5]), c);
+        assert_eq!(8, c.manhattan(&PointN([0, 3, 4, 11])));
+
+        assert_eq!(
+            None,
+            Point3::new(0, 0, i64::MAX).checked_add(&Point3::new(0, 0, 1))
+        );
+        assert_eq!(None, PointN([i64::MIN]).checked_mul(-1));
+        assert_eq!(Some(PointN([6])), PointN([3]).checked_mul(2));
+    }
+
+    #[test]
+    fn neighbors() {
+        let origin = Point3::origin();
+        assert_eq!(6, origin.adjacent().len());
+        assert_eq!(26, origin.adjacent_diagonal().len());
+        assert!(origin.adjacent().iter().all(|p| p.manhattan(&origin) == 1));
+
+        let mut flat = Coordinates::adjacent(&Point::new(0, 0));
+        let mut expected = Point::new(0, 0).adjacent();
+        flat.sort();
+        expected.sort();
+        assert_eq!(expected, flat);
+
+        assert_eq!(80, PointN::<4>::origin().adjacent_diagonal().len());
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a repeatedly stepped state starts looping, and how long the loop is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cycle {
+    /// The first step whose state is part of the loop.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step that has the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Steps `initial` until some state repeats, identifying states by `fingerprint`. The
+/// fingerprint can be anything cheaper to keep than the state itself, such as a hash or the
+/// few fields that matter, as long as equal fingerprints mean equal states. Never returns
+/// if the states don't repeat.
+pub fn find_cycle_by<S, K: Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(S) -> S,
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for i in 0.. {
+        if let Some(start) = seen.insert(fingerprint(&state), i) {
+            return Cycle {
+                start,
+                period: i - start,
+            };
+        }
+        state = step(state);
+    }
+
+    unreachable!()
+}
+
+/// Like `find_cycle_by`, using each state as its own fingerprint.
+pub fn find_cycle<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(S) -> S) -> Cycle {
+    find_cycle_by(initial, step, S::clone)
+}
+
+/// The state after `n` steps from `initial`. Once a state repeats, the remaining loops are
+/// skipped, so `n` can be far larger than could actually be simulated.
+pub fn state_at<S, K: Hash + Eq>(
+    initial: S,
+    n: usize,
+    mut step: impl FnMut(S) -> S,
+    mut fingerprint: impl FnMut(&S) -> K,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for i in 0..n {
+        if let Some(start) = seen.insert(fingerprint(&state), i) {
+            // This state is the one from `start`, so only the leftover part of a loop remains
+            for _ in 0..(n - i) % (i - start) {
+                state = step(state);
+            }
+            return state;
+        }
+        state = step(state);
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Grid, Point};
+
+    #[test]
+    fn numbers() {
+        // 3, 10, 38, 59, 17, 38, ...
+        let step = |x: u64| (x * x + 1) % 63;
+
+        let cycle = find_cycle(3, step);
+        assert_eq!(
+            Cycle {
+                start: 2,
+                period: 3
+            },
+            cycle
+        );
+        assert_eq!(1, cycle.equivalent_step(1));
+        assert_eq!(4, cycle.equivalent_step(1_000_000_000));
+
+        assert_eq!(17, state_at(3, 1_000_000_000, step, |x| *x));
+        assert_eq!(38, state_at(3, 2, step, |x| *x));
+    }
+
+    #[test]
+    fn grid_states() {
+        let grid = Grid::from_chars("ab\ncd\n");
+
+        let cycle = find_cycle(grid.clone(), |g| g.rotate_cw());
+        assert_eq!(
+            Cycle {
+                start: 0,
+                period: 4
+            },
+            cycle
+        );
+
+        // The top-left cell is enough to tell these rotations apart
+        let fingerprint = |g: &Grid<char>| g[Point::new(0, 0)];
+        assert_eq!(
+            cycle,
+            find_cycle_by(grid.clone(), |g| g.rotate_cw(), fingerprint)
+        );
+        assert_eq!(
+            grid.rotate_ccw(),
+            state_at(grid, 4_000_000_003, |g| g.rotate_cw(), fingerprint)
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::error::{parse_lines, parse_number, required, ParseError};
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Game {
+    id: i64,
+    sets: Vec<CubeSet>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Game, ParseError> {
+        let mut parts = s.split(':').map(|s| s.trim());
+
+        let header = required(parts.next(), "game id", s, s)?;
+        let id = required(header.split_ascii_whitespace().nth(1), "game id", s, header)?;
+        let id = parse_number(s, id)?;
+
+        let sets = required(parts.next(), "cube sets after ':'", s, s)?;
+        let sets = sets
+            .split(';')
+            .map(|set| CubeSet::try_from(set).map_err(|e| e.within(s, set)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+impl Game {
+    fn is_possible(&self, red: usize, green: usize, blue: usize) -> bool {
+        self.sets
+            .iter()
+            .all(|set| set.is_possible(red, green, blue))
+    }
+
+    fn min_cubes_required(&self) -> CubeSet {
+        let mut min = CubeSet::default();
+
+        for set in &self.sets {
+            if set.red > min.red {
+                min.red = set.red;
+            }
+
+            if set.green > min.green {
+                min.green = set.green;
+            }
+
+            if set.blue > min.blue {
+                min.blue = set.blue;
+            }
+        }
+
+        min
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CubeSet {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+impl TryFrom<&str> for CubeSet {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<CubeSet, ParseError> {
+        let cubes: Vec<&str> = s.split(',').map(|cube| cube.trim()).collect();
+
+        let mut set = CubeSet::default();
+
+        for cube in cubes {
+            let number = required(cube.split_ascii_whitespace().next(), "cube count", s, cube)?;
+            let number: usize = parse_number(s, number)?;
+
+            let color = required(cube.split_ascii_whitespace().nth(1), "cube color", s, cube)?;
+            match color {
+                "red" => set.red = number,
+                "green" => set.green = number,
+                "blue" => set.blue = number,
+                _ => return Err(ParseError::new("unexpected color", s, color)),
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+impl CubeSet {
+    fn is_possible(&self, red: usize, green: usize, blue: usize) -> bool {
+        self.red <= red && self.green <= green && self.blue <= blue
+    }
+
+    fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u64 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(&self, games: &Vec<Game>) -> Answer {
+        games
+            .iter()
+            .filter(|g| g.is_possible(12, 13, 14))
+            .map(|g| g.id)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(&self, games: &Vec<Game>) -> Answer {
+        games
+            .iter()
+            .map(|g| g.min_cubes_required().power())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load_test_input;
+
+    #[test]
+    fn is_possible() {
+        let cubes = CubeSet {
+            red: 20,
+            green: 8,
+            blue: 6,
+        };
+
+        let game = Game {
+            id: 1,
+            sets: vec![cubes],
+        };
+
+        assert!(!game.is_possible(12, 13, 14));
+        assert!(game.is_possible(20, 20, 20));
+    }
+
+    #[test]
+    fn min_cubes_required() {
+        let cubes = CubeSet {
+            red: 20,
+            green: 8,
+            blue: 6,
+        };
+
+        let cubes2 = CubeSet {
+            red: 12,
+            green: 9,
+            blue: 5,
+        };
+
+        let game = Game {
+            id: 1,
+            sets: vec![cubes, cubes2],
+        };
+
+        let min = game.min_cubes_required();
+
+        assert_eq!(
+            min,
+            CubeSet {
+                red: 20,
+                green: 9,
+                blue: 6
+            }
+        );
+    }
+
+    #[test]
+    fn day2_part1() {
+        let input = Day2.parse(&load_test_input(2).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(8), Day2.part1(&input));
+    }
+
+    #[test]
+    fn day2_part2() {
+        let input = Day2.parse(&load_test_input(2).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(2286), Day2.part2(&input));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day2
+            .parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple")
+            .err()
+            .unwrap();
+        assert_eq!((2, 19), (err.line, err.column));
+        assert_eq!("purple", err.text);
+
+        let err = Day2.parse("Game 1: 3 blue, x red").err().unwrap();
+        assert_eq!((1, 17), (err.line, err.column));
+
+        assert!(Day2.parse("Game 1").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::tokens::{Axis, Tokens};
+use crate::{Answer, Grid, Point, Solution};
+
+pub struct Schematic {
+    numbers: Tokens<usize>,
+    symbols: HashMap<Point, char>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u64 = 3;
+
+    type Input = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Schematic, ParseError> {
+        let grid = Grid::parse_with(input, |c| (!c.is_whitespace()).then_some(c))?;
+
+        let numbers = grid.tokens(Axis::Rows, |c| c.is_ascii_digit())?;
+        // Short lines are padded with '\0', which isn't a symbol either
+        let symbols = grid
+            .iter()
+            .filter(|(_, c)| !c.is_ascii_digit() && !matches!(c, '.' | '\0'))
+            .map(|(p, c)| (p, *c))
+            .collect();
+
+        Ok(Schematic { numbers, symbols })
+    }
+
+    fn part1(&self, schematic: &Schematic) -> Answer {
+        // Calculate the sum of any number that's adjacent to a symbol
+        schematic
+            .numbers
+            .iter()
+            .filter(|number| {
+                number.span.iter().any(|point| {
+                    point
+                        .adjacent_diagonal()
+                        .iter()
+                        .any(|adj| schematic.symbols.contains_key(adj))
+                })
+            })
+            .map(|number| number.value)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self, schematic: &Schematic) -> Answer {
+        let mut gear_ratio: i64 = 0;
+
+        // Only consider gears '*'
+        let gears = schematic
+            .symbols
+            .iter()
+            .filter(|(_, v)| **v == '*')
+            .map(|(p, _)| p);
+
+        for point in gears {
+            // Count each number once, however many of its digits touch the gear
+            let adjacent_numbers: HashSet<usize> = point
+                .adjacent_diagonal()
+                .into_iter()
+                .filter_map(|adj| schematic.numbers.index_at(adj))
+                .collect();
+
+            if adjacent_numbers.len() == 2 {
+                gear_ratio += adjacent_numbers
+                    .iter()
+                    .map(|i| schematic.numbers.tokens[*i].value as i64)
+                    .product::<i64>();
+            }
+        }
+
+        gear_ratio.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load_test_input;
+
+    #[test]
+    fn d3_p1() {
+        let input = Day3.parse(&load_test_input(3).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(4361), Day3.part1(&input));
+    }
+
+    #[test]
+    fn d3_p2() {
+        let input = Day3.parse(&load_test_input(3).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(467835), Day3.part2(&input));
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, parse_number, required, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Card {
+    winning: HashSet<usize>,
+    yours: HashSet<usize>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, ParseError> {
+        // Thankfully this is not a "pretty parsing" competition...I hope
+        let numbers = required(s.split(':').nth(1), "numbers after ':'", s, s)?;
+
+        let winning = required(numbers.split('|').next(), "winning numbers", s, numbers)?.trim();
+        let winning: HashSet<usize> = winning
+            .split_ascii_whitespace()
+            .map(|n| parse_number(s, n))
+            .collect::<Result<_, _>>()?;
+
+        let yours = required(
+            numbers.split('|').nth(1),
+            "'|' before your numbers",
+            s,
+            numbers,
+        )?;
+        let yours: HashSet<usize> = yours
+            .split_ascii_whitespace()
+            .map(|n| parse_number(s, n))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Card { winning, yours })
+    }
+}
+
+impl Card {
+    fn num_winners(&self) -> usize {
+        self.winning.intersection(&self.yours).count()
+    }
+
+    fn value(&self) -> usize {
+        if self.num_winners() == 0 {
+            return 0;
+        }
+
+        2_usize.pow((self.num_winners() - 1) as u32)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u64 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Card>, ParseError> {
+        let cards: Vec<Card> = parse_lines(input)?;
+
+        // Each card wins copies of the cards after it, which all have to exist
+        for (i, (line, card)) in input.lines().zip(&cards).enumerate() {
+            if i + card.num_winners() >= cards.len() {
+                return Err(ParseError::new(
+                    format!("wins copies of cards past the last ({})", cards.len()),
+                    line,
+                    line,
+                )
+                .on_line(i + 1));
+            }
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(&self, cards: &Vec<Card>) -> Answer {
+        cards.iter().map(|c| c.value()).sum::<usize>().into()
+    }
+
+    fn part2(&self, cards: &Vec<Card>) -> Answer {
+        let mut copies = vec![1; cards.len()];
+
+        for (id, card) in cards.iter().enumerate() {
+            let num_won = card.num_winners();
+
+            for x in id + 1..=id + num_won {
+                copies[x] += copies[id];
+            }
+        }
+
+        copies.iter().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::load_test_input;
+
+    #[test]
+    fn d4p1() {
+        let input = Day4.parse(&load_test_input(4).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(13), Day4.part1(&input));
+    }
+
+    #[test]
+    fn d4p2() {
+        let input = Day4.parse(&load_test_input(4).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(30), Day4.part2(&input));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day4.parse("Card 1: 41 48 | 41 48").err().unwrap();
+        assert_eq!((1, 1), (err.line, err.column));
+
+        let input = load_test_input(4).unwrap();
+        let truncated: String = input.lines().take(2).map(|l| format!("{}\n", l)).collect();
+        let err = Day4.parse(&truncated).err().unwrap();
+        assert_eq!((1, 1), (err.line, err.column));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::error::{parse_number, required, ParseError};
+use crate::{Answer, Solution};
+
+struct Translation(Vec<Range>);
+
+impl Translation {
+    fn new() -> Self {
+        Translation(Vec::new())
+    }
+
+    fn mapping(&self, s: usize) -> usize {
+        for range in &self.0 {
+            if let Some(m) = range.mapping(s) {
+                return m;
+            }
+        }
+
+        s
+    }
+}
+pub struct Almanac {
+    translations: Vec<Translation>,
+}
+
+impl Almanac {
+    fn min_location_range(&self, start: usize, length: usize) -> usize {
+        (start..start + length)
+            .map(|s| self.find_location(s))
+            .min()
+            .unwrap()
+    }
+
+    fn min_location_slice(&self, seeds: &[usize]) -> usize {
+        seeds.iter().map(|s| self.find_location(*s)).min().unwrap()
+    }
+
+    fn find_location(&self, seed: usize) -> usize {
+        let mut current = seed;
+        for translation in &self.translations {
+            current = translation.mapping(current);
+        }
+
+        current
+    }
+}
+
+struct Range {
+    source: usize,
+    destination: usize,
+    length: usize,
+}
+
+impl Range {
+    fn mapping(&self, s: usize) -> Option<usize> {
+        if s >= self.source && s < self.source + self.length {
+            return Some(self.destination + (s - self.source));
+        }
+
+        None
+    }
+}
+
+impl TryFrom<&str> for Almanac {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, ParseError> {
+        let mut translations: Vec<Translation> = Vec::new();
+
+        let mut current: Translation = Translation::new();
+
+        // Here lies more hacky AoC parsing - avert your eyes
+        let lines = s.lines().enumerate().skip(2);
+        for (i, line) in lines.filter(|(_, l)| !l.contains("map")) {
+            if line.is_empty() {
+                if !current.0.is_empty() {
+                    translations.push(current);
+                }
+                current = Translation::new();
+            } else {
+                let values: Vec<usize> = line
+                    .split_ascii_whitespace()
+                    .map(|d| parse_number(line, d))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| e.on_line(i + 1))?;
+
+                if values.len() != 3 {
+                    let e = ParseError::new("expected three numbers in a range", line, line);
+                    return Err(e.on_line(i + 1));
+                }
+
+                current.0.push(Range {
+                    source: values[1],
+                    destination: values[0],
+                    length: values[2],
+                });
+            }
+        }
+
+        // The final map isn't followed by a blank line
+        if !current.0.is_empty() {
+            translations.push(current);
+        }
+
+        Ok(Almanac { translations })
+    }
+}
+
+fn seed_data(input: &str) -> Result<Vec<usize>, ParseError> {
+    // I mean it's kind of beautiful in its own ugly way, right?
+    let line = input.lines().next().unwrap_or_default();
+
+    let seeds = required(line.split(':').nth(1), "seeds after ':'", line, line)?;
+    let tokens: Vec<&str> = seeds.split_ascii_whitespace().collect();
+
+    // Part two reads the seeds as (start, length) pairs, so there must be a whole number of them
+    match tokens.last() {
+        None => return Err(ParseError::new("no seeds after ':'", line, seeds)),
+        Some(last) if tokens.len() % 2 == 1 => {
+            return Err(ParseError::new(
+                "odd number of seeds; expected (start, length) pairs",
+                line,
+                last,
+            ))
+        }
+        _ => {}
+    }
+
+    let seeds: Vec<usize> = tokens
+        .iter()
+        .map(|n| parse_number(line, n))
+        .collect::<Result<_, _>>()?;
+
+    for (pair, length) in seeds.chunks(2).zip(tokens.chunks(2).map(|t| t[1])) {
+        if pair[1] == 0 {
+            return Err(ParseError::new("empty seed range", line, length));
+        }
+        if pair[0].checked_add(pair[1]).is_none() {
+            return Err(ParseError::new(
+                "seed range runs past the largest seed",
+                line,
+                length,
+            ));
+        }
+    }
+
+    Ok(seeds)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u64 = 5;
+
+    type Input = (Vec<usize>, Almanac);
+
+    fn parse(&self, input: &str) -> Result<(Vec<usize>, Almanac), ParseError> {
+        Ok((seed_data(input)?, Almanac::try_from(input)?))
+    }
+
+    fn part1(&self, (seeds, almanac): &(Vec<usize>, Almanac)) -> Answer {
+        almanac.min_location_slice(seeds).into()
+    }
+
+    fn part2(&self, (seeds, almanac): &(Vec<usize>, Almanac)) -> Answer {
+        // From inspecting the input, I don't think we want to try to build the whole slice of
+        // possible seeds like part one, so let's operate on the seed range definition instead
+        seeds
+            .chunks(2)
+            .map(|sr| almanac.min_location_range(sr[0], sr[1]))
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::load_test_input;
+
+    #[test]
+    fn d5p1() {
+        let input = Day5.parse(&load_test_input(5).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(35), Day5.part1(&input));
+    }
+
+    #[test]
+    fn d5p2() {
+        let input = Day5.parse(&load_test_input(5).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(46), Day5.part2(&input));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = load_test_input(5).unwrap();
+        let truncated = input.replace("0 15 37", "0 15");
+
+        let err = Day5.parse(&truncated).err().unwrap();
+        assert_eq!((8, 1), (err.line, err.column));
+
+        let err = Day5.parse("seeds: 79 14 55").err().unwrap();
+        assert_eq!((1, 14), (err.line, err.column));
+        assert_eq!("55", err.text);
+
+        let err = Day5.parse("seeds:").err().unwrap();
+        assert_eq!((1, 7), (err.line, err.column));
+
+        let err = Day5.parse("seeds: 79 14 55 0").err().unwrap();
+        assert_eq!((1, 17), (err.line, err.column));
+
+        let huge = format!("seeds: {} 2", usize::MAX);
+        let err = Day5.parse(&huge).err().unwrap();
+        assert_eq!("2", err.text);
+    }
+}
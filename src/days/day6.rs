@@ -0,0 +1,120 @@
+use crate::error::{parse_number, required, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Race {
+    duration: usize,
+    record: usize,
+}
+
+impl Race {
+    fn wins(&self, seconds_held: usize) -> bool {
+        (self.duration - seconds_held) * seconds_held > self.record
+    }
+
+    fn ways_to_win(&self) -> usize {
+        // The formula for distance traveled is (duration - X) * X where X is seconds held.
+        // This must be more than record to win. We can skip 0 and the total duration of the race.
+        (1..self.duration)
+            .filter(|seconds| self.wins(*seconds))
+            .count()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u64 = 6;
+
+    /// The races as printed, and the single race you get by ignoring the kerning.
+    type Input = (Vec<Race>, Race);
+
+    fn parse(&self, input: &str) -> Result<(Vec<Race>, Race), ParseError> {
+        Ok((parse_input(input)?, parse_input_kerning(input)?))
+    }
+
+    fn part1(&self, (races, _): &(Vec<Race>, Race)) -> Answer {
+        races
+            .iter()
+            .map(|r| r.ways_to_win())
+            .product::<usize>()
+            .into()
+    }
+
+    fn part2(&self, (_, race): &(Vec<Race>, Race)) -> Answer {
+        race.ways_to_win().into()
+    }
+}
+
+/// Returns the values after the label on line `n` (0-based) of the input, along with the line.
+fn values(input: &str, n: usize) -> Result<(&str, &str), ParseError> {
+    let line = input.lines().nth(n).unwrap_or_default();
+    let values = required(line.split(':').nth(1), "values after ':'", line, line)
+        .map_err(|e| e.on_line(n + 1))?;
+
+    Ok((line, values.trim()))
+}
+
+fn parse_input_kerning(input: &str) -> Result<Race, ParseError> {
+    let (line, durations) = values(input, 0)?;
+    let duration = durations
+        .split_ascii_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new("expected a number", line, durations))?;
+
+    let (line, records) = values(input, 1)?;
+    let record = records
+        .split_ascii_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new("expected a number", line, records).on_line(2))?;
+
+    Ok(Race { duration, record })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (line, durations) = values(input, 0)?;
+    let durations = durations
+        .split_ascii_whitespace()
+        .map(|d| parse_number(line, d))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    let (line, records) = values(input, 1)?;
+    let records = records
+        .split_ascii_whitespace()
+        .map(|d| parse_number(line, d))
+        .collect::<Result<Vec<usize>, _>>()
+        .map_err(|e| e.on_line(2))?;
+
+    if durations.len() != records.len() {
+        let e = ParseError::new("expected a record for every race", line, line);
+        return Err(e.on_line(2));
+    }
+
+    Ok(durations
+        .into_iter()
+        .zip(records)
+        .map(|(duration, record)| Race { duration, record })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::load_test_input;
+
+    #[test]
+    fn d6p1() {
+        let input = Day6.parse(&load_test_input(6).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(288), Day6.part1(&input));
+    }
+
+    #[test]
+    fn d6p2() {
+        let input = Day6.parse(&load_test_input(6).unwrap()).unwrap();
+
+        assert_eq!(Answer::from(71503), Day6.part2(&input));
+    }
+}
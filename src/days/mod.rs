@@ -0,0 +1,27 @@
+use crate::Day;
+
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+
+/// Every solved day, in order. Adding a day is one more entry here.
+pub const DAYS: &[&dyn Day] = &[
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+];
+
+pub fn find(day: u64) -> Option<&'static dyn Day> {
+    DAYS.iter().find(|d| d.day() == day).copied()
+}
+
+#[cfg(test)]
+mod fixtures {
+    use crate::fixtures::fixture_test;
+
+    include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+}
@@ -0,0 +1,240 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::Point;
+
+/// One of the four cardinal directions, with north pointing up the screen (towards smaller
+/// `y`).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step one square in this direction.
+    pub fn offset(self) -> Point {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Reads `U`/`D`/`L`/`R`, `^`/`v`/`<`/`>` or compass letters.
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, ParseError> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::North),
+            'R' | '>' | 'E' => Ok(Direction::East),
+            'D' | 'v' | 'S' => Ok(Direction::South),
+            'L' | '<' | 'W' => Ok(Direction::West),
+            _ => {
+                let text = c.to_string();
+                Err(ParseError::new("expected a direction", &text, &text))
+            }
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseError::new("expected a direction", s, s)),
+        }
+    }
+}
+
+/// One of the eight compass directions.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns an eighth of the way anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns an eighth of the way clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step one square in this direction.
+    pub fn offset(self) -> Point {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+/// Reads anything a `Direction` can be read from, or a two-letter compass point like `NE`.
+impl FromStr for Direction8 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+impl Point {
+    /// The point `n` squares away in `direction`.
+    pub fn step(&self, direction: impl Into<Direction8>, n: i64) -> Point {
+        *self + direction.into().offset() * n
+    }
+}
+
+/// A position and the direction it's facing, for walks where which way you're heading
+/// matters as much as where you are. Being hashable, it works as a search node.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Heading {
+    pub position: Point,
+    pub facing: Direction,
+}
+
+impl Heading {
+    pub fn new(position: Point, facing: Direction) -> Self {
+        Self { position, facing }
+    }
+
+    /// Moves `n` squares the way it's facing.
+    pub fn forward(&self, n: i64) -> Self {
+        Self::new(self.position.step(self.facing, n), self.facing)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::new(self.position, self.facing.turn_left())
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::new(self.position, self.facing.turn_right())
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::new(self.position, self.facing.reverse())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::dijkstra;
+    use crate::Grid;
+
+    #[test]
+    fn turning_and_parsing() {
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::North, Direction::West.turn_right());
+        assert_eq!(Direction::East, Direction::West.reverse());
+        assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+        assert_eq!(Direction8::SouthWest, Direction8::NorthEast.reverse());
+
+        let moves: Vec<Direction> = "U>vW".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(Direction::ALL.to_vec(), moves);
+        assert_eq!(Ok(Direction8::SouthEast), "SE".parse());
+        assert_eq!(Ok(Direction8::West), "<".parse());
+        assert!("x".parse::<Direction>().is_err());
+        assert!("UD".parse::<Direction>().is_err());
+
+        let p = Point::new(2, 3);
+        assert_eq!(Point::new(2, -2), p.step(Direction::North, 5));
+        assert_eq!(Point::new(0, 5), p.step(Direction8::SouthWest, 2));
+    }
+
+    #[test]
+    fn heading_search() {
+        // Moving forward costs 1 and turning costs 10, so it's cheapest to turn once and
+        // walk straight down rather than go round the wall and turn twice
+        let grid = Grid::from_chars("...\n.#.\n...\n");
+        let start = Heading::new(Point::new(0, 0), Direction::East);
+        let goal = Point::new(0, 2);
+
+        let path = dijkstra(
+            [start],
+            |h: &Heading| {
+                let ahead = h.forward(1);
+                let step = (grid.get(ahead.position) == Some(&'.')).then_some((ahead, 1));
+
+                step.into_iter()
+                    .chain([(h.turn_left(), 10), (h.turn_right(), 10)])
+            },
+            |h| h.position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(12, path.cost);
+        assert_eq!(4, path.nodes.len());
+        assert_eq!(Direction::South, path.nodes.last().unwrap().facing);
+    }
+}
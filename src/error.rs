@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::submit::Refusal;
+use crate::Part;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    MissingInput {
+        day: u64,
+        path: PathBuf,
+    },
+    Fetch {
+        url: String,
+        message: String,
+    },
+    MissingSession,
+    Refused {
+        day: u64,
+        part: Part,
+        reason: Refusal,
+    },
+    Parse {
+        day: u64,
+        error: ParseError,
+    },
+    InvalidFile {
+        path: PathBuf,
+        error: ParseError,
+    },
+    DayExists {
+        day: u64,
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::MissingInput { day, path } => {
+                write!(f, "no input for day {} at {}", day, path.display())
+            }
+            AocError::Fetch { url, message } => write!(f, "fetching {}: {}", url, message),
+            AocError::MissingSession => write!(
+                f,
+                "no session token configured; set AOC_SESSION or `session` in .aocrc"
+            ),
+            AocError::Refused { day, part, reason } => write!(
+                f,
+                "refusing to submit day {} part {}: {}",
+                day,
+                part.number(),
+                reason
+            ),
+            AocError::Parse { day, error } => write!(f, "day {}, {}", day, error),
+            AocError::InvalidFile { path, error } => write!(f, "{}, {}", path.display(), error),
+            AocError::DayExists { day, path } => {
+                write!(f, "day {} already exists ({})", day, path.display())
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            AocError::Parse { error, .. } | AocError::InvalidFile { error, .. } => Some(error),
+            AocError::MissingInput { .. }
+            | AocError::Fetch { .. }
+            | AocError::MissingSession
+            | AocError::Refused { .. }
+            | AocError::DayExists { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+/// A parse failure. Line and column are 1-based and relative to the text handed to the
+/// parser; callers that parse a piece of a larger input re-anchor them with `within` and
+/// `on_line` so the final error points into the original puzzle input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error about `token`, which must be a sub-slice of `line`.
+    pub fn new(message: impl Into<String>, line: &str, token: &str) -> Self {
+        Self {
+            line: 1,
+            column: column_of(line, token),
+            text: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Re-anchors an error produced while parsing `inner`, a sub-slice of the single line `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        if self.line == 1 {
+            self.column += column_of(outer, inner) - 1;
+        }
+
+        self
+    }
+
+    /// Moves an error produced while parsing a single line to `line` of the surrounding text.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns the 1-based column at which `inner` starts within `outer`.
+fn column_of(outer: &str, inner: &str) -> usize {
+    let offset = (inner.as_ptr() as usize).saturating_sub(outer.as_ptr() as usize);
+
+    outer.get(..offset).map_or(0, |s| s.chars().count()) + 1
+}
+
+/// Parses `token`, a sub-slice of `line`, as a number.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new("expected a number", line, token))
+}
+
+/// Unwraps a piece of `line` that the parser expected to find, reporting `context` if it's
+/// absent. `context` should be the sub-slice that was being searched.
+pub fn required<'a>(
+    token: Option<&'a str>,
+    what: &str,
+    line: &str,
+    context: &str,
+) -> Result<&'a str, ParseError> {
+    token.ok_or_else(|| ParseError::new(format!("missing {}", what), line, context))
+}
+
+/// Parses every line of `input` with `T`'s `TryFrom<&str>`, numbering errors by line.
+pub fn parse_lines<'a, T>(input: &'a str) -> Result<Vec<T>, ParseError>
+where
+    T: TryFrom<&'a str, Error = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| T::try_from(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn error_location() {
+        let line = "a: 12 x4";
+        let token = line.split_ascii_whitespace().nth(2).unwrap();
+
+        let err = parse_number::<u32>(line, token).unwrap_err();
+        assert_eq!((1, 7), (err.line, err.column));
+        assert_eq!("x4", err.text);
+
+        let outer = "Game 1: a: 12 x4";
+        let err = err.within(outer, &outer[8..]).on_line(3);
+        assert_eq!((3, 15), (err.line, err.column));
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        struct Num(u32);
+
+        impl TryFrom<&str> for Num {
+            type Error = ParseError;
+
+            fn try_from(s: &str) -> Result<Self, ParseError> {
+                parse_number(s, s).map(Num)
+            }
+        }
+
+        let nums: Vec<Num> = parse_lines("1\n2\n3").unwrap();
+        assert_eq!(vec![1, 2, 3], nums.iter().map(|n| n.0).collect::<Vec<_>>());
+
+        let err = parse_lines::<Num>("1\n2\r\n3\r").err().unwrap();
+        assert_eq!((3, 1), (err.line, err.column));
+        assert_eq!("3\r", err.text);
+    }
+}
@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::ParseError;
+use crate::input::{crate_dir, read_input};
+use crate::{days, AocError, Part};
+
+/// An example input, stored as `inputs/dayN_test_K.txt`, whose header declares the answers
+/// it's expected to produce. Either answer may be left out when an example only applies to
+/// one part:
+///
+/// ```text
+/// part1 = 8
+/// part2 = 2286
+/// ---
+/// Game 1: 3 blue, 4 red; ...
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fixture {
+    pub day: u64,
+    pub index: u64,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub input: String,
+}
+
+impl Fixture {
+    pub fn parse(day: u64, index: u64, s: &str) -> Result<Self, ParseError> {
+        let mut fixture = Fixture {
+            day,
+            index,
+            part1: None,
+            part2: None,
+            input: String::new(),
+        };
+
+        let mut offset = 0;
+        for (i, line) in s.split_inclusive('\n').enumerate() {
+            offset += line.len();
+            let line = line.trim_end();
+
+            if line == "---" {
+                fixture.input = s[offset..].to_string();
+                return Ok(fixture);
+            }
+
+            let error =
+                |message: &str, token: &str| ParseError::new(message, line, token).on_line(i + 1);
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `partN = answer` or `---`", line))?;
+            // An empty answer is one that isn't known yet
+            let value = Some(value.trim().trim_matches('"').to_string()).filter(|v| !v.is_empty());
+
+            match key.trim() {
+                "part1" => fixture.part1 = value,
+                "part2" => fixture.part2 = value,
+                key => return Err(error("expected part1 or part2", key)),
+            }
+        }
+
+        Err(ParseError::new("missing `---` after the header", s, ""))
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+fn fixture_path(day: u64, index: u64) -> PathBuf {
+    crate_dir().join(format!("inputs/day{}_test_{}.txt", day, index))
+}
+
+pub fn load_fixture(day: u64, index: u64) -> Result<Fixture, AocError> {
+    let path = fixture_path(day, index);
+    let contents = read_input(day, &path)?;
+
+    Fixture::parse(day, index, &contents).map_err(|error| AocError::InvalidFile { path, error })
+}
+
+/// Loads every fixture for `day`, ordered by index.
+pub fn load_fixtures(day: u64) -> Result<Vec<Fixture>, AocError> {
+    let prefix = format!("day{}_test_", day);
+
+    let mut indices: Vec<u64> = fs::read_dir(crate_dir().join("inputs"))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(&prefix)?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    indices.sort();
+
+    indices
+        .into_iter()
+        .map(|index| load_fixture(day, index))
+        .collect()
+}
+
+/// Runs the registered solution for `day` on fixture `index`, panicking on any mismatch.
+/// Only the parts the fixture declares an answer for are run, since an example written for
+/// one part often isn't valid input for the other.
+pub fn check_fixture(day: u64, index: u64) {
+    let fixture = load_fixture(day, index).unwrap();
+    let solution = days::find(day).unwrap_or_else(|| panic!("day {} is not registered", day));
+
+    for part in [Part::One, Part::Two] {
+        let Some(expected) = fixture.expected(part) else {
+            continue;
+        };
+
+        for (part, answer) in solution.run(&fixture.input, Some(part)).unwrap() {
+            assert_eq!(
+                expected,
+                answer.to_string(),
+                "day {} fixture {} part {}",
+                day,
+                index,
+                part.number()
+            );
+        }
+    }
+}
+
+/// Defines a test that checks one fixture. The build script invokes this once for every
+/// `inputs/dayN_test_K.txt` it finds.
+#[cfg(test)]
+macro_rules! fixture_test {
+    ($name:ident, $day:expr, $index:expr) => {
+        #[test]
+        fn $name() {
+            $crate::fixtures::check_fixture($day, $index);
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use fixture_test;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let fixture = Fixture::parse(1, 2, "part2 = \"abc\"\n---\nline 1\nline 2\n").unwrap();
+
+        assert_eq!(None, fixture.expected(Part::One));
+        assert_eq!(Some("abc"), fixture.expected(Part::Two));
+        assert_eq!("line 1\nline 2\n", fixture.input);
+
+        let err = Fixture::parse(1, 1, "part1 = 1\npart3 = 2\n---\n")
+            .err()
+            .unwrap();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert!(Fixture::parse(1, 1, "part1 = 1\n").is_err());
+
+        let fixture = Fixture::parse(1, 1, "part1 =\npart2 =\n---\n").unwrap();
+        assert_eq!((None, None), (fixture.part1, fixture.part2));
+    }
+
+    #[test]
+    fn load() {
+        let fixtures = load_fixtures(2).unwrap();
+
+        assert_eq!(1, fixtures[0].index);
+        assert_eq!(Some("8"), fixtures[0].expected(Part::One));
+    }
+}
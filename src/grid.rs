@@ -0,0 +1,541 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::neighborhood::Neighborhood;
+use crate::render::Renderer;
+use crate::{Point, Rect};
+
+/// A dense, rectangular grid of cells stored row-major, with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = i64> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Creates a `width` x `height` grid of default cells.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, T::default())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a `width` x `height` grid where each cell is copied from the point of this grid
+    /// that `source` maps it to.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(i64, i64) -> Point) -> Self {
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self::from_vec(width, height, cells)
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let bottom = self.max_y();
+        self.remap(self.height, self.width, |x, y| Point::new(y, bottom - x))
+    }
+
+    /// Rotates the grid a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let right = self.max_x();
+        self.remap(self.height, self.width, |x, y| Point::new(right - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let right = self.max_x();
+        self.remap(self.width, self.height, |x, y| Point::new(right - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let bottom = self.max_y();
+        self.remap(self.width, self.height, |x, y| Point::new(x, bottom - y))
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Repeats the grid `nx` times across and `ny` times down.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        self.remap(self.width * nx, self.height * ny, |x, y| {
+            self.wrap(Point::new(x, y))
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from row-major `cells`. Panics if there aren't exactly
+    /// `width * height` of them.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "grid rows must all be the same length"
+        );
+
+        Self::from_vec(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn max_x(&self) -> i64 {
+        self.width as i64 - 1
+    }
+
+    pub fn max_y(&self) -> i64 {
+        self.height as i64 - 1
+    }
+
+    /// The rectangle the grid covers, or `None` if it has no cells.
+    pub fn bounds(&self) -> Option<Rect> {
+        (!self.cells.is_empty())
+            .then(|| Rect::new(Point::new(0, 0), Point::new(self.max_x(), self.max_y())))
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point::new((offset % self.width) as i64, (offset / self.width) as i64)
+    }
+
+    /// Maps any point onto the grid, as though the grid repeated forever in every direction.
+    fn wrap(&self, p: Point) -> Point {
+        Point::new(
+            p.x.rem_euclid(self.width as i64),
+            p.y.rem_euclid(self.height as i64),
+        )
+    }
+
+    /// Gets the cell at `p`, treating the grid as an infinite plane that repeats it in every
+    /// direction. Panics if the grid is empty.
+    pub fn get_wrapping(&self, p: Point) -> &T {
+        &self[self.wrap(p)]
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Sets the cell at `p`. Panics if `p` is outside the grid.
+    pub fn insert(&mut self, p: Point, value: T) {
+        self[p] = value;
+    }
+
+    pub fn adjacent_cardinal_points(&self, point: &Point) -> Vec<Point> {
+        self.neighbors(*point, &Neighborhood::CARDINAL).collect()
+    }
+
+    pub fn all_adjacent_points(&self, point: &Point) -> Vec<Point> {
+        self.neighbors(*point, &Neighborhood::ALL).collect()
+    }
+
+    /// The points of `neighborhood` around `point` that are inside the grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Point> + 'a {
+        neighborhood.around(point).filter(|p| self.contains(*p))
+    }
+
+    /// The points of `neighborhood` around `point`, wrapping around the edges of the grid.
+    pub fn neighbors_wrapping<'a>(
+        &'a self,
+        point: Point,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Point> + 'a {
+        // An empty grid has no bounds to wrap within, and no neighbors
+        let bounds = self
+            .bounds()
+            .unwrap_or(Rect::new(Point::new(0, 0), Point::new(0, 0)));
+
+        neighborhood
+            .around_wrapping(point, bounds)
+            .filter(|p| self.contains(*p))
+    }
+
+    /// Every point in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point(i))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, v)| (Point::new((i % width) as i64, (i / width) as i64), v))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Display> Grid<T> {
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Renderer::new(self).render())
+    }
+}
+
+/// The rows of a text grid: trailing whitespace is dropped from each line, and trailing blank
+/// lines are dropped altogether.
+fn text_rows(s: &str) -> Vec<&str> {
+    let mut rows: Vec<&str> = s.lines().map(str::trim_end).collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+
+    rows
+}
+
+impl<T: Default> Grid<T> {
+    /// Creates a grid from rows of any length, padding the short ones with `T::default()`.
+    pub fn from_ragged_rows(mut rows: Vec<Vec<T>>) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize_with(width, T::default);
+        }
+
+        Self::from_rows(rows)
+    }
+
+    /// Parses a grid with one row per line, decoding each character with `decode`. Lines may
+    /// be ragged or have trailing whitespace; short rows are padded with `T::default()`. A
+    /// character that `decode` rejects is reported at its line and column.
+    pub fn parse_with(
+        s: &str,
+        mut decode: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = text_rows(s)
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.char_indices()
+                    .map(|(j, c)| {
+                        decode(c).ok_or_else(|| {
+                            ParseError::new(
+                                "unexpected character",
+                                line,
+                                &line[j..j + c.len_utf8()],
+                            )
+                            .on_line(i + 1)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::from_ragged_rows(rows))
+    }
+
+    /// Parses a grid whose cell type knows how to decode itself from a character.
+    pub fn parse(s: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::parse_with(s, |c| T::try_from(c).ok())
+    }
+
+    /// Parses a grid using `legend` to look up the cell for each character.
+    pub fn parse_legend(s: &str, legend: &[(char, T)]) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_with(s, |c| {
+            legend
+                .iter()
+                .find(|(key, _)| *key == c)
+                .map(|(_, cell)| cell.clone())
+        })
+    }
+}
+
+impl Grid<i64> {
+    /// Parses a grid of single digits.
+    pub fn from_text_grid(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, |c| c.to_digit(10).map(i64::from))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters, one row per line. Short rows are padded with `'\0'`.
+    pub fn from_chars(s: &str) -> Self {
+        Grid::from_ragged_rows(
+            text_rows(s)
+                .into_iter()
+                .map(|line| line.chars().collect())
+                .collect(),
+        )
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of ASCII bytes, one row per line. Short rows are padded with zero.
+    pub fn from_bytes(s: &str) -> Self {
+        Grid::from_ragged_rows(
+            text_rows(s)
+                .into_iter()
+                .map(|line| line.bytes().collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load_test_input;
+
+    #[test]
+    fn digits() {
+        let mut grid = Grid::from_text_grid("123\n456\n").unwrap();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[Point::new(2, 1)]);
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+
+        *grid.get_mut(Point::new(0, 0)).unwrap() = 9;
+        grid.insert(Point::new(1, 0), 8);
+        assert_eq!(
+            vec![9, 8, 3, 4, 5, 6],
+            grid.values().copied().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)],
+            {
+                let mut adj = grid.adjacent_cardinal_points(&Point::new(1, 0));
+                adj.sort_by_key(|p| (p.y, p.x));
+                adj
+            }
+        );
+        assert_eq!(5, grid.all_adjacent_points(&Point::new(1, 0)).len());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 3x2 grid")]
+    fn index_out_of_bounds() {
+        let grid = Grid::from_text_grid("123\n456").unwrap();
+        let _ = grid[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn schematic() {
+        let input = load_test_input(3).unwrap();
+
+        let chars = Grid::from_chars(&input);
+        assert_eq!((10, 10), (chars.width(), chars.height()));
+        assert_eq!('*', chars[Point::new(3, 1)]);
+
+        let bytes = Grid::from_bytes(&input);
+        assert_eq!(chars.map(|c| *c as u8), bytes);
+
+        let (p, _) = chars.iter().find(|(_, c)| **c == '#').unwrap();
+        assert_eq!(Point::new(6, 3), p);
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        #[default]
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, char> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(c),
+            }
+        }
+    }
+
+    #[test]
+    fn decode_cells() {
+        let text = "#.#  \n.#\n#..\n\n";
+
+        let grid: Grid<Tile> = Grid::parse(text).unwrap();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Tile::Wall, grid[Point::new(1, 1)]);
+        // The short middle row is padded
+        assert_eq!(Tile::Open, grid[Point::new(2, 1)]);
+
+        let legend = [('.', Tile::Open), ('#', Tile::Wall)];
+        assert_eq!(grid, Grid::parse_legend(text, &legend).unwrap());
+        assert_eq!(
+            vec![3, 1, 3],
+            Grid::parse_with(text, |c| Some(if c == '#' { 3 } else { 1 }))
+                .unwrap()
+                .rows()
+                .map(|row| row[0])
+                .collect::<Vec<_>>()
+        );
+
+        let err = Grid::<Tile>::parse("..#\n.x.\n").unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.text.as_str()));
+
+        let err = Grid::from_text_grid("12\n3*").unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = Grid::from_chars("abc\ndef\n");
+
+        assert_eq!("da\neb\nfc\n", grid.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_ccw().to_string());
+        assert_eq!("cba\nfed\n", grid.flip_horizontal().to_string());
+        assert_eq!("def\nabc\n", grid.flip_vertical().to_string());
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_cw().rotate_ccw().rotate_ccw());
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.flip_horizontal().flip_vertical()
+        );
+
+        assert_eq!(
+            "abcabc\ndefdef\nabcabc\ndefdef\nabcabc\ndefdef\n",
+            grid.tile(2, 3).to_string()
+        );
+        assert_eq!(0, grid.tile(0, 0).points().count());
+        assert_eq!('f', *grid.get_wrapping(Point::new(-1, -1)));
+        assert_eq!('b', *grid.get_wrapping(Point::new(7, 4)));
+    }
+
+    #[test]
+    fn neighborhoods() {
+        let grid = Grid::from_chars("abc\ndef\nghi\n");
+        let cells =
+            |points: Vec<Point>| -> String { points.into_iter().map(|p| grid[p]).collect() };
+
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            "bd",
+            cells(grid.neighbors(corner, &Neighborhood::CARDINAL).collect())
+        );
+        assert_eq!(
+            "bcdeg",
+            cells(
+                grid.neighbors(corner, &Neighborhood::VonNeumann(2))
+                    .collect()
+            )
+        );
+        assert_eq!(
+            "ighcbfde",
+            cells(
+                grid.neighbors_wrapping(corner, &Neighborhood::ALL)
+                    .collect()
+            )
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::AocError;
+
+pub const YEAR: u64 = 2023;
+
+pub(crate) const USER_AGENT: &str = "github.com/ameske/adventofcode2023";
+
+/// The crate's root directory, so files resolve the same wherever a binary is run from.
+pub fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The directory holding problem inputs: `AOC_INPUT_DIR` if it's set, otherwise `inputs/`
+/// in the crate.
+pub fn input_dir() -> PathBuf {
+    match env::var_os("AOC_INPUT_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => crate_dir().join("inputs"),
+    }
+}
+
+/// Where a day's problem input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input directory, fetching into it if needed.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets an `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn load(&self, day: u64) -> Result<String, AocError> {
+        match self {
+            InputSource::Default => crate::load_problem_input(day),
+            InputSource::File(path) => read_input(day, path),
+            InputSource::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            }
+        }
+    }
+}
+
+/// Reads an input file, reporting a missing file as `MissingInput` for `day`.
+pub(crate) fn read_input(day: u64, path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AocError::MissingInput {
+            day,
+            path: path.to_path_buf(),
+        },
+        _ => AocError::Io(e),
+    })
+}
+
+/// Supplies puzzle inputs from a cache directory, downloading any day that isn't cached
+/// yet. A cached day is never fetched again.
+pub struct InputProvider {
+    dir: PathBuf,
+    config: Config,
+}
+
+impl InputProvider {
+    pub fn new(dir: impl Into<PathBuf>, config: Config) -> Self {
+        Self {
+            dir: dir.into(),
+            config,
+        }
+    }
+
+    pub fn path(&self, day: u64) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    pub fn input(&self, day: u64) -> Result<String, AocError> {
+        let path = self.path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(s) => return Ok(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        // Without a session there's nothing to fetch with, so it's just missing
+        let Some(session) = &self.config.session else {
+            return Err(AocError::MissingInput { day, path });
+        };
+
+        let input = self.fetch(day, session)?;
+
+        // Write through a temporary file so an interrupted write never looks like a cached input
+        fs::create_dir_all(&self.dir)?;
+        let tmp = path.with_extension("txt.part");
+        fs::write(&tmp, &input)?;
+        fs::rename(&tmp, &path)?;
+
+        Ok(input)
+    }
+
+    fn fetch(&self, day: u64, session: &str) -> Result<String, AocError> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.config.base_url.trim_end_matches('/'),
+            YEAR,
+            day
+        );
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| AocError::Fetch {
+                url: url.clone(),
+                message: e.to_string(),
+            })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_server::{temp_dir, MockServer};
+
+    fn config(server: &MockServer, session: Option<&str>) -> Config {
+        Config {
+            session: session.map(String::from),
+            base_url: server.url.clone(),
+        }
+    }
+
+    #[test]
+    fn input_source() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+
+        let path = crate_dir().join("inputs/day6.txt");
+        let source = InputSource::from_arg(path.to_str().unwrap());
+        assert_eq!(InputSource::File(path.clone()), source);
+        assert_eq!(fs::read_to_string(&path).unwrap(), source.load(6).unwrap());
+
+        let source = InputSource::File(crate_dir().join("inputs/nope.txt"));
+        assert!(matches!(
+            source.load(6),
+            Err(AocError::MissingInput { day: 6, .. })
+        ));
+    }
+
+    #[test]
+    fn fetches_once_and_caches() {
+        let server = MockServer::start(|req| (200, format!("input for {}\n", req.path)));
+        let dir = temp_dir("fetch");
+        let provider = InputProvider::new(&dir, config(&server, Some("secret")));
+
+        let input = provider.input(7).unwrap();
+        assert_eq!("input for /2023/day/7/input\n", input);
+        assert_eq!(input, fs::read_to_string(dir.join("day7.txt")).unwrap());
+
+        assert_eq!(input, provider.input(7).unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(("GET", ""), (&*requests[0].method, &*requests[0].body));
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_without_session() {
+        let server = MockServer::start(|_| (200, String::new()));
+        let dir = temp_dir("nosession");
+        let provider = InputProvider::new(&dir, config(&server, None));
+
+        assert!(matches!(
+            provider.input(1),
+            Err(AocError::MissingInput { day: 1, .. })
+        ));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn server_error_is_not_cached() {
+        let server = MockServer::start(|_| (404, "not yet".to_string()));
+        let dir = temp_dir("notfound");
+        let provider = InputProvider::new(&dir, config(&server, Some("secret")));
+
+        assert!(matches!(provider.input(25), Err(AocError::Fetch { .. })));
+        assert!(!provider.path(25).exists());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Point, Rect};
+
+/// Which points around a cell count as its neighbors.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Neighborhood {
+    /// Every point within the given Manhattan distance.
+    VonNeumann(u32),
+    /// Every point within the given Chebyshev distance, diagonals included.
+    Moore(u32),
+    /// The points at each of these offsets.
+    Offsets(Vec<Point>),
+}
+
+impl Neighborhood {
+    /// Up, down, left and right.
+    pub const CARDINAL: Neighborhood = Neighborhood::VonNeumann(1);
+    /// The eight points surrounding a cell.
+    pub const ALL: Neighborhood = Neighborhood::Moore(1);
+
+    /// The neighbors of `center`, from the top left, row by row.
+    pub fn around(&self, center: Point) -> Neighbors<'_> {
+        Neighbors {
+            neighborhood: self,
+            center,
+            wrap: None,
+            next: 0,
+        }
+    }
+
+    /// The neighbors of `center`, with any that fall outside `bounds` wrapped around to the
+    /// opposite side as though the plane were a torus. A neighborhood wider than the bounds
+    /// can reach the same point more than once.
+    pub fn around_wrapping(&self, center: Point, bounds: Rect) -> Neighbors<'_> {
+        Neighbors {
+            wrap: Some(bounds),
+            ..self.around(center)
+        }
+    }
+
+    /// How many offsets `Neighbors` steps through, including those it skips.
+    fn candidates(&self) -> usize {
+        match self {
+            Neighborhood::VonNeumann(r) | Neighborhood::Moore(r) => (2 * *r as usize + 1).pow(2),
+            Neighborhood::Offsets(offsets) => offsets.len(),
+        }
+    }
+
+    /// The offset of candidate `i`, if it's part of the neighborhood.
+    fn offset(&self, i: usize) -> Option<Point> {
+        let (r, manhattan) = match self {
+            Neighborhood::VonNeumann(r) => (*r as i64, true),
+            Neighborhood::Moore(r) => (*r as i64, false),
+            Neighborhood::Offsets(offsets) => return Some(offsets[i]),
+        };
+
+        let side = 2 * r as usize + 1;
+        let (dx, dy) = ((i % side) as i64 - r, (i / side) as i64 - r);
+        let inside = !manhattan || dx.abs() + dy.abs() <= r;
+
+        (inside && (dx, dy) != (0, 0)).then(|| Point::new(dx, dy))
+    }
+}
+
+/// An iterator over the neighbors of a point, computed as it goes.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a> {
+    neighborhood: &'a Neighborhood,
+    center: Point,
+    wrap: Option<Rect>,
+    next: usize,
+}
+
+impl Iterator for Neighbors<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        while self.next < self.neighborhood.candidates() {
+            let i = self.next;
+            self.next += 1;
+
+            let Some(offset) = self.neighborhood.offset(i) else {
+                continue;
+            };
+            return Some(match self.wrap {
+                // Added up in i128 so a step off the edge of the i64 range still wraps
+                Some(b) => Point::new(
+                    b.wrap_coord(0, self.center.x as i128 + offset.x as i128),
+                    b.wrap_coord(1, self.center.y as i128 + offset.y as i128),
+                ),
+                None => self.center + offset,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shapes() {
+        let origin = Point::new(0, 0);
+        let sorted = |points: Vec<Point>| {
+            let mut points = points;
+            points.sort();
+            points
+        };
+
+        assert_eq!(
+            sorted(origin.adjacent()),
+            sorted(Neighborhood::CARDINAL.around(origin).collect())
+        );
+        assert_eq!(
+            sorted(origin.adjacent_diagonal()),
+            sorted(Neighborhood::ALL.around(origin).collect())
+        );
+
+        assert_eq!(12, Neighborhood::VonNeumann(2).around(origin).count());
+        assert_eq!(24, Neighborhood::Moore(2).around(origin).count());
+        assert!(Neighborhood::VonNeumann(2)
+            .around(origin)
+            .all(|p| p.x.abs() + p.y.abs() <= 2));
+
+        let knight = Neighborhood::Offsets(vec![Point::new(1, 2), Point::new(-2, 1)]);
+        assert_eq!(
+            vec![Point::new(6, 7), Point::new(3, 6)],
+            knight.around(Point::new(5, 5)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let bounds = Rect::new(Point::new(0, 0), Point::new(4, 2));
+
+        assert_eq!(
+            vec![
+                Point::new(0, 2),
+                Point::new(4, 0),
+                Point::new(1, 0),
+                Point::new(0, 1)
+            ],
+            Neighborhood::CARDINAL
+                .around_wrapping(Point::new(0, 0), bounds)
+                .collect::<Vec<_>>()
+        );
+
+        // Stepping off the edge of the i64 range lands on the other side
+        let full = Rect::new(Point::new(i64::MIN, 0), Point::new(i64::MAX, 0));
+        assert_eq!(
+            vec![
+                Point::new(i64::MAX, 0),
+                Point::new(i64::MAX - 1, 0),
+                Point::new(i64::MIN, 0),
+                Point::new(i64::MAX, 0)
+            ],
+            Neighborhood::CARDINAL
+                .around_wrapping(Point::new(i64::MAX, 0), full)
+                .collect::<Vec<_>>()
+        );
+    }
+}
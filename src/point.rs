@@ -0,0 +1,230 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::coords::Coordinates;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn adjacent(&self) -> Vec<Point> {
+        vec![
+            Point::new(self.x, self.y - 1),
+            Point::new(self.x, self.y + 1),
+            Point::new(self.x - 1, self.y),
+            Point::new(self.x + 1, self.y),
+        ]
+    }
+
+    pub fn three_by_three_grid(&self) -> Vec<Point> {
+        vec![
+            Point::new(self.x - 1, self.y - 1),
+            Point::new(self.x, self.y - 1),
+            Point::new(self.x + 1, self.y - 1),
+            Point::new(self.x - 1, self.y),
+            Point::new(self.x, self.y),
+            Point::new(self.x + 1, self.y),
+            Point::new(self.x - 1, self.y + 1),
+            Point::new(self.x, self.y + 1),
+            Point::new(self.x + 1, self.y + 1),
+        ]
+    }
+
+    /// The eight surrounding points, row by row.
+    pub fn adjacent_diagonal(&self) -> Vec<Point> {
+        Coordinates::adjacent_diagonal(self)
+    }
+
+    #[deprecated(note = "renamed to `adjacent_diagonal`")]
+    pub fn adjacent_diaganoal(&self) -> Vec<Point> {
+        self.adjacent_diagonal()
+    }
+
+    /// The number of orthogonal steps between two points.
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        Coordinates::manhattan(self, other)
+    }
+
+    /// The number of king's moves between two points.
+    pub fn chebyshev(&self, other: &Point) -> u64 {
+        Coordinates::chebyshev(self, other)
+    }
+
+    /// The square of the straight-line distance, which is exact where the distance itself
+    /// wouldn't be.
+    pub fn distance_squared(&self, other: &Point) -> u128 {
+        Coordinates::distance_squared(self, other)
+    }
+
+    /// Each coordinate reduced to -1, 0 or 1, giving the direction of a single step towards
+    /// this point.
+    pub fn signum(&self) -> Point {
+        Coordinates::signum(self)
+    }
+
+    /// Rotates a quarter turn clockwise about `origin`, as seen with y pointing down.
+    pub fn rotate_cw(&self, origin: Point) -> Point {
+        let d = *self - origin;
+        origin + Point::new(-d.y, d.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise about `origin`, as seen with y pointing down.
+    pub fn rotate_ccw(&self, origin: Point) -> Point {
+        let d = *self - origin;
+        origin + Point::new(d.y, -d.x)
+    }
+
+    pub fn checked_add(&self, other: &Point) -> Option<Point> {
+        Coordinates::checked_add(self, other)
+    }
+
+    pub fn checked_sub(&self, other: &Point) -> Option<Point> {
+        Coordinates::checked_sub(self, other)
+    }
+
+    pub fn checked_neg(&self) -> Option<Point> {
+        Some(Point::new(self.x.checked_neg()?, self.y.checked_neg()?))
+    }
+
+    pub fn checked_mul(&self, n: i64) -> Option<Point> {
+        Coordinates::checked_mul(self, n)
+    }
+
+    pub fn checked_rotate_cw(&self, origin: Point) -> Option<Point> {
+        let d = self.checked_sub(&origin)?;
+        origin.checked_add(&Point::new(d.y.checked_neg()?, d.x))
+    }
+
+    pub fn checked_rotate_ccw(&self, origin: Point) -> Option<Point> {
+        let d = self.checked_sub(&origin)?;
+        origin.checked_add(&Point::new(d.y, d.x.checked_neg()?))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i64) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -4);
+        let b = Point::new(-1, 2);
+
+        assert_eq!(Point::new(2, -2), a + b);
+        assert_eq!(Point::new(4, -6), a - b);
+        assert_eq!(Point::new(-3, 4), -a);
+        assert_eq!(Point::new(9, -12), a * 3);
+
+        let mut c = a;
+        c += b;
+        c -= Point::new(1, 1);
+        assert_eq!(Point::new(1, -3), c);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(3, -4);
+
+        assert_eq!(7, a.manhattan(&Point::new(0, 0)));
+        assert_eq!(4, a.chebyshev(&Point::new(0, 0)));
+        assert_eq!(25, a.distance_squared(&Point::new(0, 0)));
+        assert_eq!(Point::new(1, -1), a.signum());
+
+        // Written generically, the same calls give the same answers
+        fn spread<P: Coordinates>(a: &P, b: &P) -> (u64, u64) {
+            (a.manhattan(b), a.chebyshev(b))
+        }
+        assert_eq!((7, 4), spread(&a, &Point::new(0, 0)));
+        assert_eq!(8, a.adjacent_diagonal().len());
+
+        // Distances across the whole coordinate range don't overflow
+        let (left, right) = (Point::new(i64::MIN, 0), Point::new(i64::MAX, 0));
+        assert_eq!(u64::MAX, left.manhattan(&right));
+        assert_eq!((u64::MAX as u128).pow(2), left.distance_squared(&right));
+    }
+
+    #[test]
+    fn rotation() {
+        let origin = Point::new(1, 1);
+        // Right of the origin goes to below it
+        assert_eq!(Point::new(1, 3), Point::new(3, 1).rotate_cw(origin));
+        assert_eq!(Point::new(1, -1), Point::new(3, 1).rotate_ccw(origin));
+        assert_eq!(
+            Point::new(5, 2),
+            Point::new(5, 2)
+                .rotate_cw(origin)
+                .rotate_cw(origin)
+                .rotate_ccw(origin)
+                .rotate_ccw(origin)
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Point::new(i64::MAX, 0);
+
+        assert_eq!(None, max.checked_add(&Point::new(1, 0)));
+        assert_eq!(None, (-max).checked_sub(&Point::new(2, 0)));
+        assert_eq!(None, Point::new(0, i64::MIN).checked_neg());
+        assert_eq!(None, max.checked_mul(2));
+        assert_eq!(None, max.checked_rotate_cw(Point::new(0, 1)));
+        assert_eq!(
+            Some(Point::new(0, 1)),
+            Point::new(1, 0).checked_rotate_cw(Point::new(0, 0))
+        );
+        assert_eq!(
+            Some(Point::new(5, 7)),
+            Point::new(2, 3).checked_add(&Point::new(3, 4))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Measurements of simple polygons given as their vertices in order, with the last joined
+//! back to the first. Everything is computed from the vertices alone, in 128-bit
+//! arithmetic, so polygons with coordinates far too large to put in a `Grid` are fine.
+//!
+//! The lattice point counts assume a simple polygon with nonzero area. Fewer than three
+//! vertices don't make a polygon, so they count as holding no points at all.
+
+use crate::direction::Direction;
+use crate::Point;
+
+/// Where a point lies relative to a polygon.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Follows `steps` from `start`, returning the corner reached after each one. A dig plan or
+/// loop description that ends where it started gives the polygon's vertices.
+pub fn trace(start: Point, steps: impl IntoIterator<Item = (Direction, i64)>) -> Vec<Point> {
+    steps
+        .into_iter()
+        .scan(start, |p, (direction, n)| {
+            *p = p.step(direction, n);
+            Some(*p)
+        })
+        .collect()
+}
+
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the signed area, by the shoelace formula. Doubling keeps it a whole number. It's
+/// positive when the vertices run clockwise as drawn on screen, with y pointing down.
+pub fn signed_area_x2(vertices: &[Point]) -> i128 {
+    edges(vertices)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum()
+}
+
+/// The length of the boundary in a straight line from vertex to vertex.
+pub fn perimeter(vertices: &[Point]) -> f64 {
+    edges(vertices)
+        .map(|(a, b)| (a.distance_squared(&b) as f64).sqrt())
+        .sum()
+}
+
+/// The number of lattice points on the boundary. For a polygon whose edges are all
+/// horizontal or vertical, this is also its perimeter.
+pub fn boundary_points(vertices: &[Point]) -> u128 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+
+    edges(vertices)
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as u128)
+        .sum()
+}
+
+/// The number of lattice points strictly inside, by Pick's theorem. Fewer than three
+/// vertices, or vertices all on one line, enclose nothing.
+pub fn interior_points(vertices: &[Point]) -> u128 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+
+    // A = I + B/2 - 1, so 2I = 2A - B + 2. A flat polygon has no area but B > 2, so
+    // saturate rather than underflow
+    (signed_area_x2(vertices).unsigned_abs() + 2).saturating_sub(boundary_points(vertices)) / 2
+}
+
+/// The number of lattice points inside or on the boundary, which is the number of cells a
+/// loop of cells covers, including the loop itself.
+pub fn enclosed_points(vertices: &[Point]) -> u128 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+pub fn locate(vertices: &[Point], p: Point) -> Location {
+    let mut inside = false;
+
+    for (a, b) in edges(vertices) {
+        let (dx, dy) = (b.x as i128 - a.x as i128, b.y as i128 - a.y as i128);
+        let cross = dx * (p.y as i128 - a.y as i128) - (p.x as i128 - a.x as i128) * dy;
+
+        let between = |u: i64, v: i64, w: i64| u.min(v) <= w && w <= u.max(v);
+        if cross == 0 && between(a.x, b.x, p.x) && between(a.y, b.y, p.y) {
+            return Location::Boundary;
+        }
+
+        // Count the edges crossed by a ray going right from p. The crossing is to the right
+        // exactly when the cross product has the same sign as the edge's rise.
+        if (a.y > p.y) != (b.y > p.y) && (cross > 0) == (dy > 0) {
+            inside = !inside;
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lagoon() {
+        // The example dig plan from 2023 day 18
+        let plan = "R 6\nD 5\nL 2\nD 2\nR 2\nD 2\nL 5\nU 2\nL 1\nU 2\nR 2\nU 3\nL 2\nU 2";
+        let steps = plan.lines().map(|line| {
+            let (direction, n) = line.split_once(' ').unwrap();
+            (direction.parse().unwrap(), n.parse().unwrap())
+        });
+        let vertices = trace(Point::new(0, 0), steps);
+
+        assert_eq!(Some(&Point::new(0, 0)), vertices.last());
+        assert_eq!(38, boundary_points(&vertices));
+        assert_eq!(62, enclosed_points(&vertices));
+        assert_eq!(38.0, perimeter(&vertices));
+    }
+
+    #[test]
+    fn shapes() {
+        let square = [
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(0, 4),
+        ];
+        assert_eq!(32, signed_area_x2(&square));
+        let reversed: Vec<Point> = square.iter().rev().copied().collect();
+        assert_eq!(-32, signed_area_x2(&reversed));
+        assert_eq!(9, interior_points(&square));
+
+        // A triangle with a diagonal edge: 2A = 12, B = 3 + 4 + 1
+        let triangle = [Point::new(0, 0), Point::new(3, 0), Point::new(0, 4)];
+        assert_eq!(12, signed_area_x2(&triangle));
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+        assert_eq!(12.0, perimeter(&triangle));
+
+        assert_eq!(Location::Inside, locate(&triangle, Point::new(1, 1)));
+        assert_eq!(Location::Boundary, locate(&triangle, Point::new(0, 2)));
+        assert_eq!(Location::Boundary, locate(&triangle, Point::new(3, 0)));
+        assert_eq!(Location::Outside, locate(&triangle, Point::new(2, 2)));
+        assert_eq!(Location::Outside, locate(&triangle, Point::new(-1, 0)));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(0, interior_points(&[]));
+        assert_eq!(0, enclosed_points(&[]));
+
+        let segment = [Point::new(0, 0), Point::new(5, 0)];
+        assert_eq!(0, interior_points(&segment));
+        assert_eq!(0, boundary_points(&segment));
+        assert_eq!(0, enclosed_points(&segment));
+
+        let flat = [Point::new(0, 0), Point::new(5, 0), Point::new(2, 0)];
+        assert_eq!(0, interior_points(&flat));
+    }
+
+    #[test]
+    fn huge_coordinates() {
+        let n = 1 << 40;
+        let square = [
+            Point::new(-n, -n),
+            Point::new(n, -n),
+            Point::new(n, n),
+            Point::new(-n, n),
+        ];
+
+        let side = 2 * n as u128;
+        assert_eq!(2 * side * side, signed_area_x2(&square) as u128);
+        assert_eq!((side + 1) * (side + 1), enclosed_points(&square));
+        assert_eq!(Location::Inside, locate(&square, Point::new(n - 1, 0)));
+        assert_eq!(Location::Outside, locate(&square, Point::new(n + 1, 0)));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::coords::{Coordinates, Point3};
+use crate::Point;
+
+/// An axis-aligned box of points, inclusive of both corners, in any number of dimensions.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+/// A rectangle of 2D points.
+pub type Rect = Bounds<Point>;
+
+/// A cuboid of 3D points.
+pub type Cuboid = Bounds<Point3>;
+
+impl<P: Coordinates> Bounds<P> {
+    /// Creates the box spanning two opposite corners, in either order.
+    pub fn new(a: P, b: P) -> Self {
+        Self {
+            min: P::from_fn(|i| a.coord(i).min(b.coord(i))),
+            max: P::from_fn(|i| a.coord(i).max(b.coord(i))),
+        }
+    }
+
+    /// The smallest box holding every one of `points`, or `None` if there aren't any.
+    pub fn bounding(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |bounds, p| {
+            bounds.union_bound(&Self::new(p, p))
+        }))
+    }
+
+    /// The number of points along `axis`. This is a `u128` because a box spanning the whole
+    /// `i64` range holds one more point than a `u64` can count.
+    pub fn size(&self, axis: usize) -> u128 {
+        self.max.coord(axis).abs_diff(self.min.coord(axis)) as u128 + 1
+    }
+
+    /// The number of points inside. Panics if the product of the side lengths overflows a
+    /// `u128`.
+    pub fn count(&self) -> u128 {
+        (0..P::DIMENSIONS)
+            .map(|i| self.size(i))
+            .try_fold(1u128, u128::checked_mul)
+            .expect("too many points to count in a u128")
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        (0..P::DIMENSIONS).all(|i| (self.min.coord(i)..=self.max.coord(i)).contains(&p.coord(i)))
+    }
+
+    /// Maps `p` into the box, as though the box repeated forever along every axis.
+    pub fn wrap(&self, p: P) -> P {
+        P::from_fn(|i| self.wrap_coord(i, p.coord(i) as i128))
+    }
+
+    /// Wraps a coordinate along `axis` into the box. It's taken as an `i128` so callers can
+    /// step past the edge of the `i64` range before wrapping, and so a box spanning that
+    /// whole range, which is 2^64 points wide, still works.
+    pub(crate) fn wrap_coord(&self, axis: usize, v: i128) -> i64 {
+        let min = self.min.coord(axis) as i128;
+        (min + (v - min).rem_euclid(self.size(axis) as i128)) as i64
+    }
+
+    /// The points inside both boxes, if there are any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = P::from_fn(|i| self.min.coord(i).max(other.min.coord(i)));
+        let max = P::from_fn(|i| self.max.coord(i).min(other.max.coord(i)));
+
+        (0..P::DIMENSIONS)
+            .all(|i| min.coord(i) <= max.coord(i))
+            .then_some(Self { min, max })
+    }
+
+    /// The smallest box holding both boxes.
+    pub fn union_bound(&self, other: &Self) -> Self {
+        Self {
+            min: P::from_fn(|i| self.min.coord(i).min(other.min.coord(i))),
+            max: P::from_fn(|i| self.max.coord(i).max(other.max.coord(i))),
+        }
+    }
+
+    /// Splits what's left of this box after cutting `other` out of it into disjoint boxes,
+    /// at most two per axis.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        // Slice off the parts before and after the cut along each axis in turn, narrowing
+        // what remains until it's just the cut itself
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for axis in 0..P::DIMENSIONS {
+            if rest.min.coord(axis) < cut.min.coord(axis) {
+                pieces.push(Self {
+                    min: rest.min,
+                    max: with_coord(rest.max, axis, cut.min.coord(axis) - 1),
+                });
+                rest.min = with_coord(rest.min, axis, cut.min.coord(axis));
+            }
+            if rest.max.coord(axis) > cut.max.coord(axis) {
+                pieces.push(Self {
+                    min: with_coord(rest.min, axis, cut.max.coord(axis) + 1),
+                    max: rest.max,
+                });
+                rest.max = with_coord(rest.max, axis, cut.max.coord(axis));
+            }
+        }
+
+        pieces
+    }
+
+    /// Every point inside, varying the first axis fastest, so a `Rect` is read row by row.
+    pub fn points(&self) -> BoundsPoints<P> {
+        BoundsPoints {
+            bounds: *self,
+            next: Some(self.min),
+        }
+    }
+}
+
+impl Rect {
+    pub fn width(&self) -> u128 {
+        self.size(0)
+    }
+
+    pub fn height(&self) -> u128 {
+        self.size(1)
+    }
+
+    pub fn area(&self) -> u128 {
+        self.count()
+    }
+}
+
+impl Cuboid {
+    pub fn width(&self) -> u128 {
+        self.size(0)
+    }
+
+    pub fn height(&self) -> u128 {
+        self.size(1)
+    }
+
+    pub fn depth(&self) -> u128 {
+        self.size(2)
+    }
+
+    pub fn volume(&self) -> u128 {
+        self.count()
+    }
+}
+
+/// The points inside a `Bounds`, produced as they're needed.
+#[derive(Debug, Clone)]
+pub struct BoundsPoints<P> {
+    bounds: Bounds<P>,
+    next: Option<P>,
+}
+
+impl<P: Coordinates> Iterator for BoundsPoints<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        let current = self.next?;
+
+        // Count up like an odometer, rolling each axis over into the next
+        let mut next = current;
+        self.next = None;
+        for axis in 0..P::DIMENSIONS {
+            if next.coord(axis) < self.bounds.max.coord(axis) {
+                self.next = Some(with_coord(next, axis, next.coord(axis) + 1));
+                break;
+            }
+            next = with_coord(next, axis, self.bounds.min.coord(axis));
+        }
+
+        Some(current)
+    }
+}
+
+/// `p` with its coordinate along `axis` replaced by `value`.
+fn with_coord<P: Coordinates>(p: P, axis: usize, value: i64) -> P {
+    P::from_fn(|i| if i == axis { value } else { p.coord(i) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rect_algebra() {
+        let a = Rect::new(Point::new(0, 0), Point::new(3, 2));
+        let b = Rect::new(Point::new(5, -1), Point::new(2, 1));
+
+        assert_eq!((4, 3, 12), (a.width(), a.height(), a.area()));
+        assert!(a.contains(Point::new(3, 2)));
+        assert!(!a.contains(Point::new(4, 2)));
+
+        assert_eq!(
+            Some(Rect::new(Point::new(2, 0), Point::new(3, 1))),
+            a.intersection(&b)
+        );
+        assert_eq!(
+            None,
+            a.intersection(&Rect::new(Point::new(4, 0), Point::new(4, 0)))
+        );
+        assert_eq!(
+            Rect::new(Point::new(0, -1), Point::new(5, 2)),
+            a.union_bound(&b)
+        );
+
+        assert_eq!(
+            Some(b),
+            Rect::bounding([Point::new(3, 0), Point::new(5, 1), Point::new(2, -1)])
+        );
+        assert_eq!(None, Rect::bounding([]));
+
+        let points: Vec<Point> = Rect::new(Point::new(0, 0), Point::new(1, 1))
+            .points()
+            .collect();
+        assert_eq!(
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1)
+            ],
+            points
+        );
+    }
+
+    #[test]
+    fn subtract() {
+        let a = Rect::new(Point::new(0, 0), Point::new(3, 2));
+        let hole = Rect::new(Point::new(1, 1), Point::new(1, 1));
+
+        let pieces = a.subtract(&hole);
+        assert_eq!(4, pieces.len());
+        assert_eq!(a.area() - 1, pieces.iter().map(Rect::area).sum::<u128>());
+        for (i, piece) in pieces.iter().enumerate() {
+            assert_eq!(None, piece.intersection(&hole));
+            assert!(pieces[i + 1..]
+                .iter()
+                .all(|p| p.intersection(piece).is_none()));
+        }
+
+        assert_eq!(
+            vec![a],
+            a.subtract(&Rect::new(Point::new(9, 9), Point::new(9, 9)))
+        );
+        assert!(a.subtract(&a.union_bound(&hole)).is_empty());
+    }
+
+    #[test]
+    fn full_range() {
+        let line = Rect::new(Point::new(i64::MIN, 0), Point::new(i64::MAX, 0));
+        assert_eq!(1 << 64, line.width());
+        assert_eq!(1 << 64, line.area());
+
+        let plane = Rect::new(Point::new(i64::MIN, -1), Point::new(i64::MAX, i64::MAX));
+        assert_eq!((1 << 63) + 1, plane.height());
+        assert_eq!((1 << 64) * ((1 << 63) + 1), plane.area());
+
+        // Wrapping across a full-width box leaves every x where it is
+        assert_eq!(Point::new(i64::MIN, 0), line.wrap(Point::new(i64::MIN, 5)));
+        assert_eq!(
+            Point::new(i64::MAX, i64::MAX),
+            plane.wrap(Point::new(i64::MAX, -2))
+        );
+        assert_eq!(
+            Point::new(1, 2),
+            Rect::new(Point::new(0, 0), Point::new(2, 2)).wrap(Point::new(-2, -1))
+        );
+    }
+
+    #[test]
+    fn cuboids() {
+        let a = Cuboid::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2));
+        let b = Cuboid::new(Point3::new(1, 1, 1), Point3::new(4, 4, 4));
+
+        assert_eq!(27, a.volume());
+        assert_eq!(Some(8), a.intersection(&b).map(|c| c.volume()));
+        assert_eq!(27, a.points().count());
+        assert!(a.points().all(|p| a.contains(p)));
+
+        let pieces = a.subtract(&b);
+        assert_eq!(19, pieces.iter().map(Cuboid::volume).sum::<u128>());
+        assert!(pieces.iter().all(|p| p.intersection(&b).is_none()));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+
+use crate::{Grid, Point, Rect, SparseGrid};
+
+/// Anything laid out on a grid of points that a `Renderer` can draw.
+pub trait Cells {
+    type Cell;
+
+    /// The rectangle to draw, or `None` if there's nothing to draw.
+    fn bounds(&self) -> Option<Rect>;
+
+    fn cell(&self, p: Point) -> Option<&Self::Cell>;
+
+    /// The character drawn where there's no cell.
+    fn fill(&self) -> char {
+        '.'
+    }
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Option<Rect> {
+        Grid::bounds(self)
+    }
+
+    fn cell(&self, p: Point) -> Option<&T> {
+        self.get(p)
+    }
+}
+
+impl<T> Cells for SparseGrid<T> {
+    type Cell = T;
+
+    fn bounds(&self) -> Option<Rect> {
+        SparseGrid::bounds(self)
+    }
+
+    fn cell(&self, p: Point) -> Option<&T> {
+        self.get(p)
+    }
+
+    fn fill(&self) -> char {
+        SparseGrid::fill(self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// How a highlighted cell is drawn.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Highlight {
+    /// The cell as usual, wrapped in an ANSI colour.
+    Color(Color),
+    /// A marker character in place of the cell.
+    Marker(char),
+}
+
+type Format<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// Draws a grid as text, one line per row, with optional highlighted overlays. Later
+/// overlays win where they overlap.
+pub struct Renderer<'a, G: Cells> {
+    grid: &'a G,
+    format: Format<'a, G::Cell>,
+    fill: char,
+    overlays: Vec<(HashSet<Point>, Highlight)>,
+}
+
+impl<'a, G: Cells> Renderer<'a, G>
+where
+    G::Cell: Display,
+{
+    pub fn new(grid: &'a G) -> Self {
+        Self::with_format(grid, |cell| cell.to_string())
+    }
+}
+
+impl<'a, G: Cells> Renderer<'a, G> {
+    /// Creates a renderer that draws each cell with `format`.
+    pub fn with_format(grid: &'a G, format: impl Fn(&G::Cell) -> String + 'a) -> Self {
+        Self {
+            grid,
+            format: Box::new(format),
+            fill: grid.fill(),
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Sets the character drawn where there's no cell.
+    pub fn fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, style: Highlight) -> Self {
+        self.overlays.push((points.into_iter().collect(), style));
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let Some(bounds) = self.grid.bounds() else {
+            return out;
+        };
+
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let p = Point::new(x, y);
+                let cell = match self.grid.cell(p) {
+                    Some(cell) => (self.format)(cell),
+                    None => self.fill.to_string(),
+                };
+
+                let style = self
+                    .overlays
+                    .iter()
+                    .rev()
+                    .find(|(points, _)| points.contains(&p))
+                    .map(|(_, style)| style);
+
+                match style {
+                    Some(Highlight::Color(color)) => {
+                        write!(out, "\x1b[{}m{}\x1b[0m", color.code(), cell).unwrap()
+                    }
+                    Some(Highlight::Marker(marker)) => out.push(*marker),
+                    None => out.push_str(&cell),
+                }
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    pub fn print(&self) {
+        print!("{}", self.render());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn overlays() {
+        let grid = Grid::from_text_grid("123\n456\n").unwrap();
+
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!(
+            "x2x\n4xx\n",
+            Renderer::new(&grid)
+                .highlight([Point::new(0, 0), Point::new(2, 0)], Highlight::Marker('x'))
+                .highlight(
+                    grid.points().filter(|p| p.y == 1 && p.x > 0),
+                    Highlight::Marker('x')
+                )
+                .render()
+        );
+        assert_eq!(
+            "12\x1b[31m3\x1b[0m\n\x1b[32m4\x1b[0m56\n",
+            Renderer::new(&grid)
+                .highlight([Point::new(0, 1)], Highlight::Marker('#'))
+                .highlight([Point::new(2, 0)], Highlight::Color(Color::Red))
+                .highlight([Point::new(0, 1)], Highlight::Color(Color::Green))
+                .render()
+        );
+    }
+
+    #[test]
+    fn formats_and_fills() {
+        let grid = Grid::from_text_grid("19\n").unwrap();
+        assert_eq!(
+            "[1][9]\n",
+            Renderer::with_format(&grid, |v| format!("[{}]", v)).render()
+        );
+
+        let sparse: SparseGrid<bool> = [(Point::new(-1, -1), true), (Point::new(0, 0), false)]
+            .into_iter()
+            .collect();
+        let render = |g| Renderer::with_format(g, |b: &bool| if *b { "#" } else { "o" }.into());
+        assert_eq!("#.\n.o\n", render(&sparse).render());
+        assert_eq!("# \n o\n", render(&sparse).fill(' ').render());
+    }
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::AocError;
+
+const TEMPLATE: &str = "\
+use crate::error::ParseError;
+use crate::{Answer, Solution};
+
+pub struct Day{N};
+
+impl Solution for Day{N} {
+    const DAY: u64 = {N};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _input: &Vec<String>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self, _input: &Vec<String>) -> Answer {
+        Answer::Unsolved
+    }
+}
+";
+
+const FIXTURE: &str = "part1 =\npart2 =\n---\n";
+
+/// Creates the solution module and first example fixture for `day` under the crate at
+/// `root`, and registers the day in `src/days/mod.rs`. Refuses to touch a day that already
+/// has a module or a fixture. Returns the files it changed. Both parts of the new day answer
+/// `Answer::Unsolved` until they're written, which `verify` never records and `submit`
+/// refuses to send. Like any registered day, it needs an input before `run all` gets past it.
+pub fn new_day(root: &Path, day: u64) -> Result<Vec<PathBuf>, AocError> {
+    let module = root.join(format!("src/days/day{}.rs", day));
+    let fixture = root.join(format!("inputs/day{}_test_1.txt", day));
+    let registry = root.join("src/days/mod.rs");
+
+    for path in [&module, &fixture] {
+        if path.exists() {
+            return Err(AocError::DayExists {
+                day,
+                path: path.clone(),
+            });
+        }
+    }
+
+    let registered =
+        register(&fs::read_to_string(&registry)?, day).ok_or_else(|| AocError::DayExists {
+            day,
+            path: registry.clone(),
+        })?;
+
+    fs::write(&module, TEMPLATE.replace("{N}", &day.to_string()))?;
+    fs::create_dir_all(root.join("inputs"))?;
+    fs::write(&fixture, FIXTURE)?;
+    fs::write(&registry, registered)?;
+
+    Ok(vec![module, fixture, registry])
+}
+
+/// Adds `day` to the module declarations and the `DAYS` list of the registry source, keeping
+/// both in day order. Returns `None` if the day is already declared.
+fn register(source: &str, day: u64) -> Option<String> {
+    let declaration = format!("pub mod day{};", day);
+    let entry = format!("    &day{}::Day{},", day, day);
+
+    if source.lines().any(|l| l.trim() == declaration) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    insert_sorted(&mut lines, declaration, |l| {
+        l.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    });
+    insert_sorted(&mut lines, entry, |l| {
+        l.trim()
+            .strip_prefix("&day")?
+            .split("::")
+            .next()?
+            .parse()
+            .ok()
+    });
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the run of lines for which `day_of` returns a day number, before
+/// the first one with a larger number.
+fn insert_sorted(lines: &mut Vec<String>, line: String, day_of: impl Fn(&str) -> Option<u64>) {
+    let day = day_of(&line);
+
+    let position = lines
+        .iter()
+        .position(|l| day_of(l).is_some_and(|d| Some(d) > day))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|l| day_of(l).is_some())
+                .map(|i| i + 1)
+        })
+        .unwrap_or(lines.len());
+
+    lines.insert(position, line);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_server::temp_dir;
+
+    const REGISTRY: &str = "\
+use crate::Day;
+
+pub mod day2;
+pub mod day6;
+
+pub const DAYS: &[&dyn Day] = &[
+    &day2::Day2,
+    &day6::Day6,
+];
+";
+
+    #[test]
+    fn register_in_order() {
+        let source = register(REGISTRY, 4).unwrap();
+        let source = register(&source, 10).unwrap();
+
+        assert_eq!(
+            "\
+use crate::Day;
+
+pub mod day2;
+pub mod day4;
+pub mod day6;
+pub mod day10;
+
+pub const DAYS: &[&dyn Day] = &[
+    &day2::Day2,
+    &day4::Day4,
+    &day6::Day6,
+    &day10::Day10,
+];
+",
+            source
+        );
+
+        assert_eq!(None, register(REGISTRY, 6));
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = temp_dir("scaffold");
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let written = new_day(&root, 7).unwrap();
+        assert_eq!(3, written.len());
+
+        let module = fs::read_to_string(root.join("src/days/day7.rs")).unwrap();
+        assert!(module.contains("pub struct Day7;"));
+        assert!(!module.contains("todo!()"));
+        assert_eq!(
+            FIXTURE,
+            fs::read_to_string(root.join("inputs/day7_test_1.txt")).unwrap()
+        );
+
+        assert!(matches!(
+            new_day(&root, 7),
+            Err(AocError::DayExists { day: 7, .. })
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
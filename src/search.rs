@@ -0,0 +1,342 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::{Grid, Point};
+
+/// The cheapest route found by a search, from a start node to the goal inclusive.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<N> {
+    pub cost: u64,
+    pub nodes: Vec<N>,
+}
+
+/// The number of steps from the nearest of `starts` to every node reachable from them.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let next = distances[&node] + 1;
+        for neighbor in neighbors(&node) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
+/// The shortest path from any of `starts` to a node satisfying `is_goal`, where every step
+/// costs one.
+pub fn bfs_path<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // Numbered as in `astar`, so each node's parent is an index rather than a clone
+    let mut nodes: Vec<N> = Vec::new();
+    let mut index: HashMap<N, usize> = HashMap::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !index.contains_key(&start) {
+            index.insert(start.clone(), nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push(start);
+            parents.push(None);
+        }
+    }
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&nodes[i]) {
+            return Some(reconstruct(&nodes, &parents, i));
+        }
+
+        for neighbor in neighbors(&nodes[i]) {
+            if !index.contains_key(&neighbor) {
+                index.insert(neighbor.clone(), nodes.len());
+                queue.push_back(nodes.len());
+                nodes.push(neighbor);
+                parents.push(Some(i));
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from any of `starts` to a node satisfying `is_goal`, where `neighbors`
+/// gives each node's successors and the cost of stepping to them.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbors, |_| 0, is_goal)
+}
+
+/// Like `dijkstra`, but guided by `heuristic`, an estimate of the remaining cost from a node
+/// to the goal. The path is only guaranteed to be the cheapest if the heuristic never
+/// overestimates.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    // Nodes are numbered as they're discovered so the heap needn't order them
+    let mut nodes: Vec<N> = Vec::new();
+    let mut index: HashMap<N, usize> = HashMap::new();
+    let mut costs: Vec<u64> = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if index.contains_key(&start) {
+            continue;
+        }
+
+        heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+        index.insert(start.clone(), nodes.len());
+        nodes.push(start);
+        costs.push(0);
+        parents.push(None);
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+
+        if is_goal(&nodes[i]) {
+            return Some(Path {
+                cost,
+                ..reconstruct(&nodes, &parents, i)
+            });
+        }
+
+        for (neighbor, step) in neighbors(&nodes[i]) {
+            let next = cost + step;
+
+            let j = match index.get(&neighbor) {
+                Some(&j) if costs[j] <= next => continue,
+                Some(&j) => j,
+                None => {
+                    index.insert(neighbor.clone(), nodes.len());
+                    nodes.push(neighbor);
+                    costs.push(u64::MAX);
+                    parents.push(None);
+                    nodes.len() - 1
+                }
+            };
+
+            costs[j] = next;
+            parents[j] = Some(i);
+            heap.push(Reverse((next + heuristic(&nodes[j]), next, j)));
+        }
+    }
+
+    None
+}
+
+/// Follows `parents` back from node `i` to a start, giving the path and its length in steps.
+fn reconstruct<N: Clone>(nodes: &[N], parents: &[Option<usize>], i: usize) -> Path<N> {
+    let mut path = vec![nodes[i].clone()];
+    let mut current = i;
+    while let Some(parent) = parents[current] {
+        path.push(nodes[parent].clone());
+        current = parent;
+    }
+    path.reverse();
+
+    Path {
+        cost: path.len() as u64 - 1,
+        nodes: path,
+    }
+}
+
+/// Searches over the grid's cardinal neighbors.
+impl<T> Grid<T> {
+    /// The number of steps from the nearest of `starts` to every reachable point, only
+    /// stepping onto points for which `passable` holds.
+    pub fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        mut passable: impl FnMut(Point, &T) -> bool,
+    ) -> HashMap<Point, usize> {
+        bfs(starts, |p| {
+            self.adjacent_cardinal_points(p)
+                .into_iter()
+                .filter(|q| passable(*q, &self[*q]))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// The shortest path from any of `starts` to `goal`, only stepping onto points for which
+    /// `passable` holds.
+    pub fn bfs_path(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        goal: Point,
+        mut passable: impl FnMut(Point, &T) -> bool,
+    ) -> Option<Path<Point>> {
+        bfs_path(
+            starts,
+            |p| {
+                self.adjacent_cardinal_points(p)
+                    .into_iter()
+                    .filter(|q| passable(*q, &self[*q]))
+                    .collect::<Vec<_>>()
+            },
+            |p| *p == goal,
+        )
+    }
+
+    /// The cheapest path from any of `starts` to `goal`, where `cost` gives the cost of
+    /// stepping between two adjacent points, or `None` if the step isn't allowed.
+    pub fn dijkstra(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        goal: Point,
+        cost: impl FnMut(Point, Point) -> Option<u64>,
+    ) -> Option<Path<Point>> {
+        self.astar(starts, goal, cost, |_| 0)
+    }
+
+    /// Like `dijkstra`, guided by `heuristic`'s estimate of the cost from a point to `goal`.
+    pub fn astar(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        goal: Point,
+        mut cost: impl FnMut(Point, Point) -> Option<u64>,
+        heuristic: impl FnMut(&Point) -> u64,
+    ) -> Option<Path<Point>> {
+        astar(
+            starts,
+            |p| {
+                self.adjacent_cardinal_points(p)
+                    .into_iter()
+                    .filter_map(|q| Some((q, cost(*p, q)?)))
+                    .collect::<Vec<_>>()
+            },
+            heuristic,
+            |p| *p == goal,
+        )
+    }
+}
+
+impl<T: Copy + Into<i64>> Grid<T> {
+    /// The cheapest path from `start` to `goal` where entering a cell costs its value, as in
+    /// a digit grid from `from_text_grid`. Cells with negative values can't be entered.
+    pub fn cheapest_path(&self, start: Point, goal: Point) -> Option<Path<Point>> {
+        self.dijkstra([start], goal, |_, to| u64::try_from(self[to].into()).ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: &str = "\
+S.#.
+..#.
+.#..
+...E
+";
+
+    #[test]
+    fn bfs_distances() {
+        let grid = Grid::from_chars(MAZE);
+        let open = |_, c: &char| *c != '#';
+
+        let distances = grid.bfs([Point::new(0, 0)], open);
+        assert_eq!(Some(&6), distances.get(&Point::new(3, 3)));
+        assert_eq!(Some(&9), distances.get(&Point::new(3, 0)));
+        assert_eq!(None, distances.get(&Point::new(2, 0)));
+
+        let distances = grid.bfs([Point::new(0, 0), Point::new(3, 0)], open);
+        assert_eq!(Some(&3), distances.get(&Point::new(3, 3)));
+
+        let path = grid
+            .bfs_path([Point::new(0, 0)], Point::new(3, 0), open)
+            .unwrap();
+        assert_eq!(9, path.cost);
+        assert_eq!(10, path.nodes.len());
+        assert!(path.nodes.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert_eq!(
+            None,
+            grid.bfs_path([Point::new(0, 0)], Point::new(2, 0), open)
+        );
+    }
+
+    #[test]
+    fn weighted() {
+        let grid = Grid::from_text_grid("1163\n1381\n2136\n").unwrap();
+        let goal = Point::new(3, 2);
+
+        let path = grid.cheapest_path(Point::new(0, 0), goal).unwrap();
+        assert_eq!(13, path.cost);
+        assert_eq!(Point::new(0, 0), path.nodes[0]);
+        assert_eq!(Some(&goal), path.nodes.last());
+        assert_eq!(
+            path.cost,
+            path.nodes[1..].iter().map(|p| grid[*p] as u64).sum::<u64>()
+        );
+
+        let manhattan = |p: &Point| (goal.x - p.x + goal.y - p.y) as u64;
+        let astar = grid
+            .astar(
+                [Point::new(0, 0)],
+                goal,
+                |_, to| Some(grid[to] as u64),
+                manhattan,
+            )
+            .unwrap();
+        assert_eq!(13, astar.cost);
+
+        // Cells of 6 or more are walls, which shut (3, 1) off from the start even though
+        // it's open itself
+        let walled = grid.dijkstra([Point::new(0, 0)], Point::new(3, 1), |_, to| {
+            (grid[to] < 6).then_some(1)
+        });
+        assert_eq!(None, walled);
+    }
+
+    #[test]
+    fn generic_nodes() {
+        // Reach 10 from 1 by doubling (cost 1) or adding one (cost 3)
+        let path = dijkstra([1u64], |&n| [(n * 2, 1), (n + 1, 3)], |&n| n == 10).unwrap();
+        assert_eq!(vec![1, 2, 4, 5, 10], path.nodes);
+        assert_eq!(6, path.cost);
+    }
+}
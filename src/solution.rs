@@ -0,0 +1,240 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::bench::Sample;
+use crate::error::{AocError, ParseError};
+
+/// A puzzle solution. The input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u64;
+
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    fn part2(&self, input: &Self::Input) -> Answer;
+}
+
+/// Object-safe view of a `Solution`, used by the day registry and the runner.
+pub trait Day {
+    fn day(&self) -> u64;
+
+    /// Parses `input` and solves the requested part, or both parts for `None`.
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Vec<(Part, Answer)>, AocError>;
+
+    /// Parses and solves both parts once, timing each step separately.
+    fn time(&self, input: &str) -> Result<Sample, AocError>;
+}
+
+impl<S: Solution> Day for S {
+    fn day(&self) -> u64 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Vec<(Part, Answer)>, AocError> {
+        let input = self
+            .parse(input)
+            .map_err(|error| AocError::Parse { day: S::DAY, error })?;
+
+        let mut answers = Vec::new();
+        if part != Some(Part::Two) {
+            answers.push((Part::One, self.part1(&input)));
+        }
+        if part != Some(Part::One) {
+            answers.push((Part::Two, self.part2(&input)));
+        }
+
+        Ok(answers)
+    }
+
+    fn time(&self, input: &str) -> Result<Sample, AocError> {
+        let start = Instant::now();
+        let input = self
+            .parse(black_box(input))
+            .map_err(|error| AocError::Parse { day: S::DAY, error })?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part1(&input));
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part2(&input));
+        let part2 = start.elapsed();
+
+        Ok(Sample {
+            parse,
+            part1,
+            part2,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("invalid part: {} (expected 1 or 2)", n)),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle. Numeric answers compare by value regardless of
+/// which integer variant produced them.
+#[derive(Debug, Eq, Clone)]
+pub enum Answer {
+    Int(i64),
+    UInt(u128),
+    Str(String),
+    /// A part that hasn't been written yet. It's never recorded or submitted.
+    Unsolved,
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::UInt(a), Answer::UInt(b)) => a == b,
+            (Answer::Int(a), Answer::UInt(b)) | (Answer::UInt(b), Answer::Int(a)) => {
+                u128::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Answer::Str(a), Answer::Str(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::UInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::UInt(n as u128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::UInt(n as u128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::UInt(n as u128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::UInt(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u64 = 0;
+
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(&self, input: &Vec<String>) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(&self, input: &Vec<String>) -> Answer {
+            input.concat().into()
+        }
+    }
+
+    #[test]
+    fn run_selected_parts() {
+        let answers = Lines.run("ab\ncd", None).unwrap();
+        assert_eq!(
+            answers,
+            vec![(Part::One, Answer::UInt(2)), (Part::Two, "abcd".into())]
+        );
+
+        let answers = Lines.run("ab\ncd", Some(Part::Two)).unwrap();
+        assert_eq!(answers, vec![(Part::Two, "abcd".into())]);
+    }
+
+    #[test]
+    fn numeric_equality() {
+        assert_eq!(Answer::from(42), Answer::from(42_usize));
+        assert_ne!(Answer::from(-1), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(42), Answer::from("42"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("-5", Answer::from(-5).to_string());
+        assert_eq!(
+            "340282366920938463463374607431768211455",
+            Answer::from(u128::MAX).to_string()
+        );
+        assert_eq!("ABC", Answer::from("ABC").to_string());
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display};
+
+use crate::neighborhood::Neighborhood;
+use crate::rect::Rect;
+use crate::render::Renderer;
+use crate::Point;
+
+/// A grid that only stores the cells that have been set, so it can grow in any direction,
+/// including into negative coordinates. Its bounds are kept up to date on every insert and
+/// remove, rather than found by scanning the cells.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T = i64> {
+    cells: HashMap<Point, T>,
+    // How many cells are in each column and row, so the extremes are always the first and
+    // last keys.
+    columns: BTreeMap<i64, usize>,
+    rows: BTreeMap<i64, usize>,
+    fill: char,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            columns: BTreeMap::new(),
+            rows: BTreeMap::new(),
+            fill: '.',
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the character rendered for absent cells, `.` by default.
+    pub fn with_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn fill(&self) -> char {
+        self.fill
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    /// Gets the cell at `p`, treating the grid as an infinite plane that repeats its bounding
+    /// rectangle in every direction.
+    pub fn get_wrapping(&self, p: Point) -> Option<&T> {
+        let bounds = self.bounds()?;
+
+        self.get(bounds.wrap(p))
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Sets the cell at `p`, returning the value it replaced.
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        let old = self.cells.insert(p, value);
+        if old.is_none() {
+            *self.columns.entry(p.x).or_default() += 1;
+            *self.rows.entry(p.y).or_default() += 1;
+        }
+
+        old
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        let old = self.cells.remove(&p)?;
+        release(&mut self.columns, p.x);
+        release(&mut self.rows, p.y);
+
+        Some(old)
+    }
+
+    pub fn min_x(&self) -> Option<i64> {
+        self.columns.keys().next().copied()
+    }
+
+    pub fn max_x(&self) -> Option<i64> {
+        self.columns.keys().next_back().copied()
+    }
+
+    pub fn min_y(&self) -> Option<i64> {
+        self.rows.keys().next().copied()
+    }
+
+    pub fn max_y(&self) -> Option<i64> {
+        self.rows.keys().next_back().copied()
+    }
+
+    /// The smallest rectangle holding every cell, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Rect> {
+        Some(Rect::new(
+            Point::new(self.min_x()?, self.min_y()?),
+            Point::new(self.max_x()?, self.max_y()?),
+        ))
+    }
+
+    pub fn adjacent_cardinal_points(&self, point: &Point) -> Vec<Point> {
+        self.neighbors(*point, &Neighborhood::CARDINAL).collect()
+    }
+
+    pub fn all_adjacent_points(&self, point: &Point) -> Vec<Point> {
+        self.neighbors(*point, &Neighborhood::ALL).collect()
+    }
+
+    /// The points of `neighborhood` around `point` that are set.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Point> + 'a {
+        neighborhood.around(point).filter(|p| self.contains(*p))
+    }
+
+    /// The points that are set, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.cells.iter_mut().map(|(p, v)| (*p, v))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Builds a grid with each cell moved to wherever `to` sends its point.
+    fn remap(&self, to: impl Fn(Point) -> Point) -> Self {
+        let mut grid: Self = self.iter().map(|(p, v)| (to(p), v.clone())).collect();
+        grid.fill = self.fill;
+        grid
+    }
+
+    /// Rotates the grid a quarter turn clockwise about the origin.
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(|p| Point::new(-p.y, p.x))
+    }
+
+    /// Rotates the grid a quarter turn anticlockwise about the origin.
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(|p| Point::new(p.y, -p.x))
+    }
+
+    /// Mirrors the grid left to right across the y axis.
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(|p| Point::new(-p.x, p.y))
+    }
+
+    /// Mirrors the grid top to bottom across the x axis.
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(|p| Point::new(p.x, -p.y))
+    }
+
+    /// Swaps x and y.
+    pub fn transpose(&self) -> Self {
+        self.remap(|p| Point::new(p.y, p.x))
+    }
+
+    /// Repeats the bounding rectangle `nx` times across and `ny` times down, starting from
+    /// where it already is. Like `Grid::tile`, a count of zero gives an empty grid. Panics if
+    /// the copies would extend past the `i64` range.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        let mut grid = Self::new().with_fill(self.fill);
+        let Some(bounds) = self.bounds() else {
+            return grid;
+        };
+        // Offsets are worked out in i128, since a single copy of a grid spanning the whole
+        // i64 range is fine but any further copy lies off the plane
+        let shift = |v: i64, n: usize, size: u128| {
+            i64::try_from(v as i128 + n as i128 * size as i128)
+                .expect("tiled grid extends past the i64 range")
+        };
+
+        for i in 0..nx {
+            for j in 0..ny {
+                for (p, v) in self.iter() {
+                    let q = Point::new(
+                        shift(p.x, i, bounds.width()),
+                        shift(p.y, j, bounds.height()),
+                    );
+                    grid.insert(q, v.clone());
+                }
+            }
+        }
+
+        grid
+    }
+}
+
+/// Takes one off the number of cells at `key`, forgetting the key once none are left.
+pub(crate) fn release(counts: &mut BTreeMap<i64, usize>, key: i64) {
+    if let Some(count) = counts.get_mut(&key) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(&key);
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (p, value) in iter {
+            grid.insert(p, value);
+        }
+
+        grid
+    }
+}
+
+impl<T> From<HashMap<Point, T>> for SparseGrid<T> {
+    fn from(cells: HashMap<Point, T>) -> Self {
+        cells.into_iter().collect()
+    }
+}
+
+impl<T: Display> SparseGrid<T> {
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+}
+
+/// Renders the bounding rectangle row by row, whatever its origin, with the fill character
+/// standing in for absent cells.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Renderer::new(self).render())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_changes() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        grid.insert(Point::new(0, 0), 1);
+        grid.insert(Point::new(-3, 2), 2);
+        grid.insert(Point::new(4, -1), 3);
+        assert_eq!(None, grid.insert(Point::new(4, 2), 4));
+        assert_eq!(Some(4), grid.insert(Point::new(4, 2), 5));
+
+        assert_eq!(
+            Some(Rect::new(Point::new(-3, -1), Point::new(4, 2))),
+            grid.bounds()
+        );
+
+        // (4, 2) still holds the right edge up after (4, -1) goes
+        assert_eq!(Some(3), grid.remove(Point::new(4, -1)));
+        assert_eq!(None, grid.remove(Point::new(4, -1)));
+        assert_eq!((Some(4), Some(0)), (grid.max_x(), grid.min_y()));
+
+        grid.remove(Point::new(4, 2));
+        assert_eq!(
+            Some(Rect::new(Point::new(-3, 0), Point::new(0, 2))),
+            grid.bounds()
+        );
+        assert_eq!(2, grid.len());
+    }
+
+    #[test]
+    fn render_from_any_origin() {
+        let grid: SparseGrid<char> = [
+            (Point::new(-2, -1), '#'),
+            (Point::new(0, -1), '#'),
+            (Point::new(-1, 0), '@'),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!("#.#\n.@.\n", grid.to_string());
+        assert_eq!("# #\n @ \n", grid.with_fill(' ').to_string());
+        assert_eq!("", SparseGrid::<char>::new().to_string());
+    }
+
+    #[test]
+    fn transforms() {
+        let grid: SparseGrid<char> = [(Point::new(-1, 0), 'a'), (Point::new(1, 1), 'b')]
+            .into_iter()
+            .collect();
+
+        assert_eq!("a..\n..b\n", grid.to_string());
+        assert_eq!(".a\n..\nb.\n", grid.rotate_cw().to_string());
+        assert_eq!(
+            Some(Rect::new(Point::new(-1, -1), Point::new(0, 1))),
+            grid.rotate_cw().bounds()
+        );
+        assert_eq!(".b\n..\na.\n", grid.rotate_ccw().to_string());
+        assert_eq!("..a\nb..\n", grid.flip_horizontal().to_string());
+        assert_eq!("..b\na..\n", grid.flip_vertical().to_string());
+        assert_eq!("a.\n..\n.b\n", grid.transpose().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+
+        assert_eq!("a..a..\n..b..b\n", grid.tile(2, 1).to_string());
+        assert_eq!(grid, grid.tile(1, 1));
+        assert!(grid.tile(0, 0).is_empty());
+        assert!(grid.tile(2, 0).is_empty());
+        assert_eq!(Some(&'b'), grid.get_wrapping(Point::new(4, -1)));
+        assert_eq!(None, grid.get_wrapping(Point::new(3, 0)));
+    }
+
+    #[test]
+    fn full_range() {
+        let grid: SparseGrid<char> = [
+            (Point::new(i64::MIN, 0), 'a'),
+            (Point::new(i64::MAX, 1), 'b'),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(Some(&'a'), grid.get_wrapping(Point::new(i64::MIN, 2)));
+        assert_eq!(Some(&'b'), grid.get_wrapping(Point::new(i64::MAX, -1)));
+        assert_eq!(None, grid.get_wrapping(Point::new(0, 0)));
+
+        let tiled = grid.tile(1, 2);
+        assert_eq!(4, tiled.len());
+        assert_eq!(Some(&'b'), tiled.get(Point::new(i64::MAX, 3)));
+    }
+}
@@ -0,0 +1,405 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::input::{USER_AGENT, YEAR};
+use crate::{Answer, AocError, Part};
+
+/// The server's judgement of a submitted answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    Unrecognized,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the server's HTML response.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: parse_wait(body),
+            }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unrecognized
+        }
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited { .. } => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unrecognized => "unrecognized",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Self {
+        match tag {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "rate_limited" => Verdict::RateLimited {
+                wait: Duration::ZERO,
+            },
+            "wrong_level" => Verdict::WrongLevel,
+            _ => Verdict::Unrecognized,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { wait } => write!(f, "rate limited, wait {:?}", wait),
+            Verdict::WrongLevel => write!(f, "part already solved or not yet unlocked"),
+            Verdict::Unrecognized => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Pulls the wait time out of "You have 1m 5s left to wait".
+fn parse_wait(body: &str) -> Duration {
+    let Some(end) = body.find("left to wait") else {
+        return Duration::ZERO;
+    };
+    let Some(start) = body[..end].rfind("You have") else {
+        return Duration::ZERO;
+    };
+
+    let seconds = body[start + "You have".len()..end]
+        .split_ascii_whitespace()
+        .filter_map(|token| {
+            let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum();
+
+    Duration::from_secs(seconds)
+}
+
+/// Why a submission was refused locally, without contacting the server.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Refusal {
+    Unsolved,
+    AlreadySolved { answer: String },
+    KnownWrong,
+    NotBelow { too_high: i128 },
+    NotAbove { too_low: i128 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Unsolved => write!(f, "the part isn't solved yet"),
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Refusal::KnownWrong => write!(f, "answer was already rejected"),
+            Refusal::NotBelow { too_high } => write!(f, "{} was already too high", too_high),
+            Refusal::NotAbove { too_low } => write!(f, "{} was already too low", too_low),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day and what the server said about it, stored as one
+/// tab-separated `part answer verdict` line per submission.
+pub struct SubmissionLog {
+    path: PathBuf,
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let submissions = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                let part = fields.next()?.parse::<u8>().ok()?;
+                let answer = fields.next()?.to_string();
+                let verdict = Verdict::from_tag(fields.next()?);
+
+                Some(Submission {
+                    part: Part::try_from(part).ok()?,
+                    answer,
+                    verdict,
+                })
+            })
+            .collect();
+
+        Ok(Self { path, submissions })
+    }
+
+    /// Checks `answer` against everything already learned about `part`.
+    pub fn check(&self, part: Part, answer: &Answer) -> Result<(), Refusal> {
+        if *answer == Answer::Unsolved {
+            return Err(Refusal::Unsolved);
+        }
+
+        let answer = answer.to_string();
+        let value: Option<i128> = answer.parse().ok();
+
+        for s in self.submissions.iter().filter(|s| s.part == part) {
+            let known: Option<i128> = s.answer.parse().ok();
+
+            match (&s.verdict, value, known) {
+                (Verdict::Correct, _, _) => {
+                    return Err(Refusal::AlreadySolved {
+                        answer: s.answer.clone(),
+                    })
+                }
+                (Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect, _, _)
+                    if s.answer == answer =>
+                {
+                    return Err(Refusal::KnownWrong)
+                }
+                (Verdict::TooHigh, Some(v), Some(too_high)) if v >= too_high => {
+                    return Err(Refusal::NotBelow { too_high })
+                }
+                (Verdict::TooLow, Some(v), Some(too_low)) if v <= too_low => {
+                    return Err(Refusal::NotAbove { too_low })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: Part, answer: &Answer, verdict: Verdict) -> io::Result<()> {
+        let submission = Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        };
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}",
+            submission.part.number(),
+            submission.answer,
+            submission.verdict.tag()
+        )?;
+
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+}
+
+/// Posts answers to the puzzle server, consulting and updating each day's
+/// `day{N}_submissions.txt` log in `dir`.
+pub struct Submitter {
+    dir: PathBuf,
+    config: Config,
+}
+
+impl Submitter {
+    pub fn new(dir: impl Into<PathBuf>, config: Config) -> Self {
+        Self {
+            dir: dir.into(),
+            config,
+        }
+    }
+
+    pub fn log_path(&self, day: u64) -> PathBuf {
+        self.dir.join(format!("day{}_submissions.txt", day))
+    }
+
+    pub fn submit(&self, day: u64, part: Part, answer: &Answer) -> Result<Verdict, AocError> {
+        let mut log = SubmissionLog::load(self.log_path(day))?;
+
+        log.check(part, answer)
+            .map_err(|reason| AocError::Refused { day, part, reason })?;
+
+        let session = self
+            .config
+            .session
+            .as_ref()
+            .ok_or(AocError::MissingSession)?;
+
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.config.base_url.trim_end_matches('/'),
+            YEAR,
+            day
+        );
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[
+                ("level", &part.number().to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(|e| AocError::Fetch {
+                url: url.clone(),
+                message: e.to_string(),
+            })?;
+
+        let verdict = Verdict::from_response(&response.into_string()?);
+        log.record(part, answer, verdict.clone())?;
+
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_server::{temp_dir, MockServer};
+
+    #[test]
+    fn verdicts() {
+        let wrong = "<p>That's not the right answer; your answer is too high.  If you're stuck";
+        assert_eq!(Verdict::TooHigh, Verdict::from_response(wrong));
+        assert_eq!(
+            Verdict::Correct,
+            Verdict::from_response("<p>That's the right answer!  You are one gold star closer")
+        );
+        assert_eq!(
+            Verdict::RateLimited {
+                wait: Duration::from_secs(65)
+            },
+            Verdict::from_response(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            )
+        );
+        assert_eq!(
+            Verdict::Unrecognized,
+            Verdict::from_response("<html></html>")
+        );
+    }
+
+    #[test]
+    fn refuses_known_bounds() {
+        let dir = temp_dir("log");
+        let mut log = SubmissionLog::load(dir.join("day1_submissions.txt")).unwrap();
+
+        log.record(Part::One, &Answer::from(100), Verdict::TooHigh)
+            .unwrap();
+        log.record(Part::One, &Answer::from(10), Verdict::TooLow)
+            .unwrap();
+        log.record(Part::One, &Answer::from(50), Verdict::Incorrect)
+            .unwrap();
+
+        let log = SubmissionLog::load(dir.join("day1_submissions.txt")).unwrap();
+        assert_eq!(3, log.submissions.len());
+
+        assert_eq!(
+            Err(Refusal::NotBelow { too_high: 100 }),
+            log.check(Part::One, &Answer::from(150))
+        );
+        assert_eq!(
+            Err(Refusal::NotAbove { too_low: 10 }),
+            log.check(Part::One, &Answer::from(3))
+        );
+        assert_eq!(Err(Refusal::KnownWrong), log.check(Part::One, &50.into()));
+        assert_eq!(Ok(()), log.check(Part::One, &Answer::from(42)));
+        assert_eq!(Ok(()), log.check(Part::Two, &Answer::from(150)));
+        assert_eq!(
+            Err(Refusal::Unsolved),
+            log.check(Part::Two, &Answer::Unsolved)
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submit_and_remember() {
+        let server = MockServer::start(|req| {
+            if req.body.contains("answer=7") {
+                (200, "That's the right answer!".to_string())
+            } else {
+                (200, "That's not the right answer.".to_string())
+            }
+        });
+        let dir = temp_dir("submit");
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.url.clone(),
+        };
+        let submitter = Submitter::new(&dir, config);
+
+        assert_eq!(
+            Verdict::Incorrect,
+            submitter.submit(3, Part::Two, &Answer::from(5)).unwrap()
+        );
+        assert!(matches!(
+            submitter.submit(3, Part::Two, &Answer::from(5)),
+            Err(AocError::Refused {
+                reason: Refusal::KnownWrong,
+                ..
+            })
+        ));
+        assert_eq!(
+            Verdict::Correct,
+            submitter.submit(3, Part::Two, &Answer::from(7)).unwrap()
+        );
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!("/2023/day/3/answer", requests[0].path);
+        assert_eq!("level=2&answer=5", requests[0].body);
+        assert_eq!(Some(USER_AGENT), requests[0].header("User-Agent"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
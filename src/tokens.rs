@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::{Grid, Point};
+
+/// Which way a grid is read when scanning for tokens.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    /// Left to right along each row.
+    Rows,
+    /// Top to bottom down each column.
+    Columns,
+}
+
+/// A maximal run of matching cells along a row or column.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Token<V> {
+    pub text: String,
+    pub value: V,
+    /// The points the token covers, in reading order.
+    pub span: Vec<Point>,
+}
+
+/// The tokens found in a grid, with a lookup from each covered point back to its token.
+#[derive(Debug, Clone)]
+pub struct Tokens<V> {
+    pub tokens: Vec<Token<V>>,
+    index: HashMap<Point, usize>,
+}
+
+impl<V> Tokens<V> {
+    /// The position in `tokens` of the token covering `p`.
+    pub fn index_at(&self, p: Point) -> Option<usize> {
+        self.index.get(&p).copied()
+    }
+
+    pub fn at(&self, p: Point) -> Option<&Token<V>> {
+        Some(&self.tokens[self.index_at(p)?])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Token<V>> {
+        self.tokens.iter()
+    }
+}
+
+impl Grid<char> {
+    /// Finds every maximal run of characters for which `matches` holds, reading along `axis`,
+    /// and parses each run's text as a `V`. A run that doesn't parse is reported at its first
+    /// character.
+    pub fn tokens<V: FromStr>(
+        &self,
+        axis: Axis,
+        mut matches: impl FnMut(char) -> bool,
+    ) -> Result<Tokens<V>, ParseError> {
+        let (lines, length) = match axis {
+            Axis::Rows => (self.height(), self.width()),
+            Axis::Columns => (self.width(), self.height()),
+        };
+        let point = |line: usize, i: usize| match axis {
+            Axis::Rows => Point::new(i as i64, line as i64),
+            Axis::Columns => Point::new(line as i64, i as i64),
+        };
+
+        let mut tokens = Tokens {
+            tokens: Vec::new(),
+            index: HashMap::new(),
+        };
+        for line in 0..lines {
+            let mut span = Vec::new();
+
+            // One past the end closes off a token that runs to the edge
+            for i in 0..=length {
+                let p = point(line, i);
+                if i < length && matches(self[p]) {
+                    span.push(p);
+                    continue;
+                }
+                if span.is_empty() {
+                    continue;
+                }
+
+                let span = std::mem::take(&mut span);
+                let text: String = span.iter().map(|p| self[*p]).collect();
+                let value = text.parse().map_err(|_| ParseError {
+                    line: span[0].y as usize + 1,
+                    column: span[0].x as usize + 1,
+                    text: text.clone(),
+                    message: "invalid token".to_string(),
+                })?;
+
+                for p in &span {
+                    tokens.index.insert(*p, tokens.tokens.len());
+                }
+                tokens.tokens.push(Token { text, value, span });
+            }
+        }
+
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan() {
+        let grid = Grid::from_chars("12.3\n4..5\n.678\n");
+        let digit = |c: char| c.is_ascii_digit();
+
+        let rows: Tokens<u32> = grid.tokens(Axis::Rows, digit).unwrap();
+        let values: Vec<u32> = rows.iter().map(|t| t.value).collect();
+        assert_eq!(vec![12, 3, 4, 5, 678], values);
+        assert_eq!(
+            vec![Point::new(1, 2), Point::new(2, 2), Point::new(3, 2)],
+            rows.tokens[4].span
+        );
+        assert_eq!(Some(4), rows.index_at(Point::new(2, 2)));
+        assert_eq!("12", rows.at(Point::new(1, 0)).unwrap().text);
+        assert_eq!(None, rows.at(Point::new(2, 0)));
+
+        let columns: Tokens<u32> = grid.tokens(Axis::Columns, digit).unwrap();
+        let values: Vec<u32> = columns.iter().map(|t| t.value).collect();
+        assert_eq!(vec![14, 2, 6, 7, 358], values);
+
+        let err = grid.tokens::<u8>(Axis::Rows, |_| true).unwrap_err();
+        assert_eq!((1, 1, "12.3"), (err.line, err.column, err.text.as_str()));
+    }
+}
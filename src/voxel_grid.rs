@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::coords::{Coordinates, Point3};
+use crate::rect::Cuboid;
+use crate::sparse_grid::release;
+
+/// The 3D counterpart to `SparseGrid`: only the voxels that have been set are stored, and
+/// the bounds along each axis are kept up to date on every insert and remove.
+#[derive(Debug, Clone)]
+pub struct VoxelGrid<T = i64> {
+    cells: HashMap<Point3, T>,
+    // How many voxels share each coordinate, per axis
+    axes: [BTreeMap<i64, usize>; 3],
+}
+
+impl<T> Default for VoxelGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            axes: Default::default(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for VoxelGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for VoxelGrid<T> {}
+
+impl<T> VoxelGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Point3) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point3) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Sets the voxel at `p`, returning the value it replaced.
+    pub fn insert(&mut self, p: Point3, value: T) -> Option<T> {
+        let old = self.cells.insert(p, value);
+        if old.is_none() {
+            for (axis, counts) in self.axes.iter_mut().enumerate() {
+                *counts.entry(p.coord(axis)).or_default() += 1;
+            }
+        }
+
+        old
+    }
+
+    pub fn remove(&mut self, p: Point3) -> Option<T> {
+        let old = self.cells.remove(&p)?;
+        for (axis, counts) in self.axes.iter_mut().enumerate() {
+            release(counts, p.coord(axis));
+        }
+
+        Some(old)
+    }
+
+    /// The smallest coordinate along each axis, or `None` if the grid is empty.
+    pub fn min(&self) -> Option<Point3> {
+        Some(Point3::new(
+            *self.axes[0].keys().next()?,
+            *self.axes[1].keys().next()?,
+            *self.axes[2].keys().next()?,
+        ))
+    }
+
+    /// The largest coordinate along each axis, or `None` if the grid is empty.
+    pub fn max(&self) -> Option<Point3> {
+        Some(Point3::new(
+            *self.axes[0].keys().next_back()?,
+            *self.axes[1].keys().next_back()?,
+            *self.axes[2].keys().next_back()?,
+        ))
+    }
+
+    /// The smallest cuboid holding every voxel, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Cuboid> {
+        Some(Cuboid::new(self.min()?, self.max()?))
+    }
+
+    /// The set voxels sharing a face with `p`.
+    pub fn adjacent_points(&self, p: Point3) -> Vec<Point3> {
+        p.adjacent()
+            .into_iter()
+            .filter(|q| self.contains(*q))
+            .collect()
+    }
+
+    /// The set voxels touching `p` by a face, edge or corner.
+    pub fn all_adjacent_points(&self, p: Point3) -> Vec<Point3> {
+        p.adjacent_diagonal()
+            .into_iter()
+            .filter(|q| self.contains(*q))
+            .collect()
+    }
+
+    /// The points that are set, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point3> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point3, &mut T)> {
+        self.cells.iter_mut().map(|(p, v)| (*p, v))
+    }
+}
+
+impl<T> FromIterator<(Point3, T)> for VoxelGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point3, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (p, value) in iter {
+            grid.insert(p, value);
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn voxels() {
+        // Two 1x1x3 bricks, one standing on the end of the other
+        let mut grid: VoxelGrid<char> = (0..3)
+            .map(|x| (Point3::new(x, 0, 1), 'A'))
+            .chain((2..5).map(|z| (Point3::new(2, 0, z), 'B')))
+            .collect();
+
+        assert_eq!(6, grid.len());
+        assert_eq!(Some(Point3::new(0, 0, 1)), grid.min());
+        assert_eq!(Some(Point3::new(2, 0, 4)), grid.max());
+        assert_eq!(Some(12), grid.bounds().map(|b| b.volume()));
+
+        assert_eq!(2, grid.adjacent_points(Point3::new(2, 0, 1)).len());
+        assert_eq!(5, grid.all_adjacent_points(Point3::new(1, 0, 2)).len());
+
+        // Surface area is the faces not shared with another voxel
+        let exposed: usize = grid
+            .points()
+            .map(|p| 6 - grid.adjacent_points(p).len())
+            .sum();
+        assert_eq!(26, exposed);
+
+        for z in 3..5 {
+            grid.remove(Point3::new(2, 0, z));
+        }
+        assert_eq!(Some(Point3::new(2, 0, 2)), grid.max());
+        assert_eq!(None, grid.remove(Point3::new(2, 0, 4)));
+    }
+}